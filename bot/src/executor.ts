@@ -302,7 +302,7 @@ export class LiquidationExecutor {
       // Fetch current pool state
       const pool = await this.vultrClient.fetchPool(this.config.poolAddress);
 
-      if (pool.isPaused) {
+      if (pool.liquidationsPaused) {
         throw new Error("Pool is paused - cannot execute liquidations");
       }
 
@@ -226,7 +226,7 @@ class VultrBot {
           `${poolState.treasuryFeeBps / 100}% treasury`
         );
 
-        if (poolState.isPaused) {
+        if (poolState.liquidationsPaused) {
           this.logger.warn("Pool is currently PAUSED. Liquidations will not execute.");
         }
       } catch {
@@ -256,7 +256,7 @@ class VultrBot {
       // Get pool state
       const poolState = await this.executor.getPoolState();
 
-      if (poolState.isPaused) {
+      if (poolState.liquidationsPaused) {
         this.logger.warn("Pool is currently PAUSED. Liquidations will not execute.");
       }
 
@@ -297,7 +297,7 @@ class VultrBot {
       // Check pool status (skip in dry-run mode if pool doesn't exist)
       if (!this.config.dryRun) {
         const poolState = await this.executor.getPoolState();
-        if (poolState.isPaused) {
+        if (poolState.liquidationsPaused) {
           this.logger.debug("Pool is paused, skipping iteration");
           return;
         }
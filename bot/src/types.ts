@@ -324,6 +324,8 @@ export interface PoolState {
   totalProfit: BN;
   /** Total liquidations executed */
   totalLiquidations: BN;
+  /** Expected nonce for the next record_profit (replay protection) */
+  nonce: BN;
   /** Depositor fee share (8000 = 80%) */
   depositorFeeBps: number;
   /** Staking fee share (1500 = 15%) */
@@ -332,6 +334,8 @@ export interface PoolState {
   treasuryFeeBps: number;
   /** Whether pool is paused */
   isPaused: boolean;
+  /** Whether record_profit is blocked (full pause or the liquidations pause flag) */
+  liquidationsPaused: boolean;
   /** Maximum pool size */
   maxPoolSize: BN;
 }
@@ -11,6 +11,9 @@ import idl from "../idl/vultr.json";
 import { PoolState } from "../types";
 import { Logger } from "../logger";
 
+/** Pool.pause_flags bit that blocks record_profit */
+const PAUSE_LIQUIDATIONS = 1 << 2;
+
 // =============================================================================
 // VULTR Client
 // =============================================================================
@@ -67,10 +70,14 @@ export class VultrClient {
         totalShares: account.totalShares as BN,
         totalProfit: account.totalProfit as BN,
         totalLiquidations: account.totalLiquidations as BN,
+        nonce: account.nonce as BN,
         depositorFeeBps: account.depositorFeeBps as number,
         stakingFeeBps: account.stakingFeeBps as number,
         treasuryFeeBps: account.treasuryFeeBps as number,
         isPaused: account.isPaused as boolean,
+        liquidationsPaused:
+          (account.isPaused as boolean) ||
+          ((account.pauseFlags as number) & PAUSE_LIQUIDATIONS) !== 0,
         maxPoolSize: account.maxPoolSize as BN,
       };
     } catch (error) {
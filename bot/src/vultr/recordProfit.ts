@@ -14,7 +14,6 @@ import {
   sendAndConfirmTransaction,
 } from "@solana/web3.js";
 import {
-  TOKEN_PROGRAM_ID,
   getAssociatedTokenAddress,
   getOrCreateAssociatedTokenAccount,
 } from "@solana/spl-token";
@@ -91,10 +90,15 @@ export class RecordProfitBuilder {
       `Building record_profit tx for ${profitAmount.toNumber() / 1_000_000} USDC`
     );
 
+    // The deposit mint may live under Token or Token-2022
+    const tokenProgram = await this.getTokenProgramId(pool.depositMint);
+
     // Get bot's profit source token account (where USDC profit sits)
     const profitSource = await getAssociatedTokenAddress(
       pool.depositMint,
-      this.wallet.publicKey
+      this.wallet.publicKey,
+      false,
+      tokenProgram
     );
 
     this.logger.debug(`Profit source ATA: ${profitSource.toBase58()}`);
@@ -111,16 +115,19 @@ export class RecordProfitBuilder {
     );
 
     // Build record_profit instruction using Anchor
+    // The pool nonce makes a duplicate submission fail with StaleNonce
+    // instead of recording the same profit twice
     const recordProfitIx = await this.program.methods
-      .recordProfit(profitAmount)
+      .recordProfit(profitAmount, pool.nonce)
       .accounts({
         botWallet: this.wallet.publicKey,
         pool: poolAddress,
+        depositMint: pool.depositMint,
         vault: pool.vault,
         stakingRewardsVault: pool.stakingRewardsVault,
         treasury: pool.treasury,
         profitSource,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram,
       })
       .instruction();
 
@@ -233,16 +240,30 @@ export class RecordProfitBuilder {
     };
   }
 
+  /**
+   * Get the token program that owns the deposit mint
+   */
+  private async getTokenProgramId(depositMint: PublicKey): Promise<PublicKey> {
+    const info = await this.connection.getAccountInfo(depositMint);
+    if (!info) throw new Error(`Deposit mint not found: ${depositMint.toBase58()}`);
+    return info.owner;
+  }
+
   /**
    * Ensure profit source ATA exists for the bot wallet
    */
   private async ensureProfitSourceAta(depositMint: PublicKey): Promise<PublicKey> {
     try {
+      const tokenProgram = await this.getTokenProgramId(depositMint);
       const ata = await getOrCreateAssociatedTokenAccount(
         this.connection,
         this.wallet,
         depositMint,
-        this.wallet.publicKey
+        this.wallet.publicKey,
+        false,
+        undefined,
+        undefined,
+        tokenProgram
       );
       return ata.address;
     } catch (error) {
@@ -19,7 +19,9 @@ pub const USDC_DECIMALS: u8 = 6;
 // Safety limits
 pub const MIN_STAKE_AMOUNT: u64 = 1_000_000; // 1 VLTR (6 decimals)
 pub const MAX_STAKE_AMOUNT: u64 = 100_000_000_000_000; // 100M VLTR
-pub const MIN_DISTRIBUTE_AMOUNT: u64 = 1_000; // 0.001 USDC minimum distribution
+// Minimum distribution is 0.001 whole reward tokens, scaled to the reward
+// mint's decimals at init (1_000 base units for 6-decimal USDC)
+pub const MIN_DISTRIBUTE_FRACTION_DECIMALS: u8 = 3;
 
 // =============================================================================
 // SECURITY FIX-16: Reward distribution cap to prevent overflow edge cases
@@ -28,3 +30,76 @@ pub const MIN_DISTRIBUTE_AMOUNT: u64 = 1_000; // 0.001 USDC minimum distribution
 // Set to 10M USDC to prevent potential overflow in reward calculations
 // while still allowing for substantial reward distributions
 pub const MAX_REWARD_PER_DISTRIBUTION: u64 = 10_000_000_000_000; // 10M USDC (6 decimals)
+
+// Largest reward_per_token increase a single distribution may cause: the
+// maximum distribution spread over MIN_STAKE_AMOUNT of effective stake.
+// Stake can be unstaked below the minimum, so without this a dust position
+// could take a whole distribution and push reward_per_token toward overflow.
+pub const MAX_REWARD_PER_TOKEN_INCREASE: u128 =
+    MAX_REWARD_PER_DISTRIBUTION as u128 * REWARD_PRECISION / MIN_STAKE_AMOUNT as u128;
+
+// =============================================================================
+// Launch Boost
+// =============================================================================
+// Early stakers earn rewards on staked * multiplier, decaying linearly to 1x
+// at boost_end_time. Multipliers are in basis points (10_000 = 1x).
+pub const BOOST_BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_BOOST_MULTIPLIER_BPS: u16 = 30_000; // 3x
+
+// =============================================================================
+// Unstake Cooldown
+// =============================================================================
+// When a cooldown is configured, stakers must call request_unstake and wait
+// this long before unstaking, so stake cannot be parked just for a distribute.
+pub const MAX_UNSTAKE_COOLDOWN_SECONDS: i64 = 30 * 24 * 60 * 60; // 30 days
+
+// =============================================================================
+// Reward Eligibility
+// =============================================================================
+// Rewards accrued within min_stake_duration_for_rewards of a position's last
+// stake change are forfeited to the other stakers if the position is changed
+// or claimed before the duration passes. Stops stake-distribute-claim sniping.
+pub const MAX_MIN_STAKE_DURATION_FOR_REWARDS: i64 = 30 * 24 * 60 * 60; // 30 days
+
+// =============================================================================
+// Bonus Rewards
+// =============================================================================
+// Reward index 0 is the primary reward (reward_mint / reward_vault) set at
+// initialize. Indices 1..MAX_REWARD_TOKENS are optional bonus reward tokens,
+// each with its own PDA vault: ["reward_vault", staking_pool, reward_mint].
+pub const MAX_REWARD_TOKENS: usize = 3;
+pub const MAX_BONUS_REWARDS: usize = MAX_REWARD_TOKENS - 1;
+
+// =============================================================================
+// Streaming Rewards
+// =============================================================================
+// distribute_streamed pays an amount out linearly over a duration instead of
+// all at once, so staking right before a distribution earns almost nothing.
+pub const MAX_REWARD_DURATION_SECONDS: i64 = 365 * 24 * 60 * 60; // 1 year
+
+// =============================================================================
+// Lockups
+// =============================================================================
+// Stakers may lock their position for a fixed term in exchange for a reward
+// multiplier (basis points, 10_000 = 1x). Stacks with the launch boost.
+pub const LOCK_TIERS: [(i64, u16); 3] = [
+    (30 * 24 * 60 * 60, 12_500),  // 30 days: 1.25x
+    (90 * 24 * 60 * 60, 15_000),  // 90 days: 1.5x
+    (180 * 24 * 60 * 60, 20_000), // 180 days: 2x
+];
+
+// =============================================================================
+// Admin Transfer
+// =============================================================================
+// Admin changes are proposed by the current admin and accepted by the new
+// admin after the timelock, so a mistyped or lost key can never take control.
+pub const ADMIN_TIMELOCK_SECONDS: i64 = 24 * 60 * 60; // 24 hours
+pub const PENDING_CHANGE_EXPIRY_SECONDS: i64 = 7 * 24 * 60 * 60; // 7 days
+
+// =============================================================================
+// Batch Distribution
+// =============================================================================
+// distribute_batch funds several staking pools from one source in a single
+// transaction. Each entry uses two remaining accounts (pool, reward vault),
+// so the batch size is bounded by the transaction's account limit anyway.
+pub const MAX_BATCH_DISTRIBUTIONS: usize = 8;
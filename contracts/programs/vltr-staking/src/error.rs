@@ -60,4 +60,93 @@ pub enum StakingError {
 
     #[msg("Invalid token account owner")]
     InvalidTokenAccountOwner,
+
+    // Boost Errors (6050-6059)
+    #[msg("Invalid boost configuration")]
+    InvalidBoostConfig,
+
+    // Maintenance Errors (6060-6069)
+    #[msg("Staker accounts do not add up to the pool's total stake")]
+    StakerSetMismatch,
+
+    // Cooldown Errors (6070-6079)
+    #[msg("Unstake cooldown has not elapsed")]
+    UnstakeCooldownNotElapsed,
+
+    #[msg("No unstake requested: call request_unstake first")]
+    UnstakeNotRequested,
+
+    #[msg("Unstake cooldown exceeds maximum")]
+    InvalidUnstakeCooldown,
+
+    // Reward Eligibility Errors (6080-6089)
+    #[msg("Staker has not been staked long enough to be eligible for rewards")]
+    RewardsNotYetEligible,
+
+    #[msg("Minimum stake duration exceeds maximum")]
+    InvalidMinStakeDuration,
+
+    // Bonus Reward Errors (6090-6099)
+    #[msg("Invalid or unconfigured reward index")]
+    InvalidRewardIndex,
+
+    #[msg("All bonus reward slots are in use")]
+    RewardTokenLimitReached,
+
+    #[msg("Reward mint is already distributed by this pool")]
+    DuplicateRewardMint,
+
+    // Streaming Errors (6100-6109)
+    #[msg("Invalid reward stream duration")]
+    InvalidRewardDuration,
+
+    // Lockup Errors (6110-6119)
+    #[msg("Stake is locked")]
+    StakeLocked,
+
+    #[msg("Lock duration must be one of the lock tiers")]
+    InvalidLockDuration,
+
+    #[msg("An existing lock cannot be shortened or downgraded")]
+    LockCannotBeShortened,
+
+    // Admin Transfer Errors (6120-6129)
+    #[msg("No pending change")]
+    NoPendingChange,
+
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+
+    #[msg("Timelock has not expired yet")]
+    TimelockNotExpired,
+
+    #[msg("Pending change has expired - propose again")]
+    TimelockExpired,
+
+    // Batch Distribution Errors (6130-6139)
+    #[msg("Batch must pair each amount with a writable staking pool and reward vault")]
+    InvalidBatch,
+
+    // Distribution Errors (6140-6149)
+    #[msg("No stakers to distribute rewards to")]
+    NoStakersToDistribute,
+
+    #[msg("Distribution is too large for the amount currently staked")]
+    RewardPerTokenIncreaseExceedsMax,
+
+    // Emergency Unstake Errors (6150-6159)
+    #[msg("Staking pool is not paused")]
+    PoolNotPaused,
+
+    // Recount Errors (6160-6169)
+    #[msg("Staker account passed more than once")]
+    DuplicateStakerAccount,
+
+    // Migration Errors (6170-6179)
+    #[msg("Account is not in the original layout or was already migrated")]
+    AlreadyMigrated,
+
+    // Distribution Limit Errors (6180-6189)
+    #[msg("Distribution exceeds MAX_REWARD_PER_DISTRIBUTION")]
+    ExceedsMaxDistribution,
 }
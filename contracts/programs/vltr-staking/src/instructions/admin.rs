@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, Mint, SetAuthority, Token, TokenAccount};
 
-use crate::constants::STAKING_POOL_SEED;
+use crate::constants::{
+    ADMIN_TIMELOCK_SECONDS, BOOST_BPS_DENOMINATOR, MAX_BOOST_MULTIPLIER_BPS,
+    MAX_MIN_STAKE_DURATION_FOR_REWARDS, MAX_UNSTAKE_COOLDOWN_SECONDS,
+    PENDING_CHANGE_EXPIRY_SECONDS, REWARD_VAULT_SEED, STAKER_SEED, STAKING_POOL_SEED,
+};
 use crate::error::StakingError;
-use crate::state::StakingPool;
+use crate::state::{BonusReward, Staker, StakingPool};
 
 // =============================================================================
 // Pause Pool
@@ -36,11 +41,13 @@ pub fn pause_pool(ctx: Context<PausePool>, paused: bool) -> Result<()> {
 }
 
 // =============================================================================
-// Transfer Admin
+// Admin Transfer (propose -> accept)
 // =============================================================================
+// The current admin proposes; the new admin must sign accept_admin_transfer
+// between ADMIN_TIMELOCK_SECONDS and PENDING_CHANGE_EXPIRY_SECONDS later.
 
 #[derive(Accounts)]
-pub struct TransferAdmin<'info> {
+pub struct ProposeAdminTransfer<'info> {
     #[account(
         constraint = admin.key() == staking_pool.admin @ StakingError::Unauthorized
     )]
@@ -61,19 +68,93 @@ pub struct TransferAdmin<'info> {
     pub new_admin: UncheckedAccount<'info>,
 }
 
-pub fn transfer_admin(ctx: Context<TransferAdmin>) -> Result<()> {
-    let old_admin = ctx.accounts.staking_pool.admin;
-    ctx.accounts.staking_pool.admin = ctx.accounts.new_admin.key();
+pub fn propose_admin_transfer(ctx: Context<ProposeAdminTransfer>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let staking_pool = &mut ctx.accounts.staking_pool;
+
+    staking_pool.pending_admin = ctx.accounts.new_admin.key();
+    staking_pool.admin_change_timestamp = now;
 
     msg!(
-        "Admin transferred from {} to {}",
-        old_admin,
-        ctx.accounts.new_admin.key()
+        "Admin transfer to {} proposed, acceptable from {}",
+        staking_pool.pending_admin,
+        now + ADMIN_TIMELOCK_SECONDS
     );
 
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct AcceptAdminTransfer<'info> {
+    /// The proposed admin must sign, proving it controls the key
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+}
+
+pub fn accept_admin_transfer(ctx: Context<AcceptAdminTransfer>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let staking_pool = &mut ctx.accounts.staking_pool;
+
+    require!(
+        staking_pool.pending_admin != Pubkey::default(),
+        StakingError::NoPendingChange
+    );
+    require!(
+        ctx.accounts.new_admin.key() == staking_pool.pending_admin,
+        StakingError::NotPendingAdmin
+    );
+
+    let elapsed = now - staking_pool.admin_change_timestamp;
+    require!(elapsed >= ADMIN_TIMELOCK_SECONDS, StakingError::TimelockNotExpired);
+    require!(elapsed <= PENDING_CHANGE_EXPIRY_SECONDS, StakingError::TimelockExpired);
+
+    let old_admin = staking_pool.admin;
+    staking_pool.admin = staking_pool.pending_admin;
+    staking_pool.pending_admin = Pubkey::default();
+    staking_pool.admin_change_timestamp = 0;
+
+    msg!("Admin transferred from {} to {}", old_admin, staking_pool.admin);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminTransfer<'info> {
+    #[account(
+        constraint = admin.key() == staking_pool.admin @ StakingError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+}
+
+pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+
+    require!(
+        staking_pool.pending_admin != Pubkey::default(),
+        StakingError::NoPendingChange
+    );
+
+    msg!("Admin transfer to {} cancelled", staking_pool.pending_admin);
+
+    staking_pool.pending_admin = Pubkey::default();
+    staking_pool.admin_change_timestamp = 0;
+
+    Ok(())
+}
+
 // =============================================================================
 // Update Reward Vault
 // =============================================================================
@@ -111,3 +192,383 @@ pub fn update_reward_vault(ctx: Context<UpdateRewardVault>) -> Result<()> {
 
     Ok(())
 }
+
+// =============================================================================
+// Set Reward Vault Authority
+// =============================================================================
+// Hands ownership of the reward vault to the staking pool PDA.
+// Once the PDA owns the vault, claims are signed by the program and no longer
+// need the off-chain vault owner to co-sign.
+
+#[derive(Accounts)]
+pub struct SetRewardVaultAuthority<'info> {
+    #[account(
+        constraint = admin.key() == staking_pool.admin @ StakingError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Current owner of the reward vault (may be the same key as admin)
+    pub current_authority: Signer<'info>,
+
+    #[account(
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        constraint = reward_vault.key() == staking_pool.reward_vault @ StakingError::InvalidPDA,
+        constraint = reward_vault.owner == current_authority.key() @ StakingError::InvalidTokenAccountOwner,
+        constraint = reward_vault.owner != staking_pool.key() @ StakingError::InvalidAuthority
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn set_reward_vault_authority(ctx: Context<SetRewardVaultAuthority>) -> Result<()> {
+    let staking_pool_key = ctx.accounts.staking_pool.key();
+
+    token::set_authority(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.current_authority.to_account_info(),
+                account_or_mint: ctx.accounts.reward_vault.to_account_info(),
+            },
+        ),
+        AuthorityType::AccountOwner,
+        Some(staking_pool_key),
+    )?;
+
+    msg!(
+        "Reward vault {} authority moved from {} to staking pool {}",
+        ctx.accounts.reward_vault.key(),
+        ctx.accounts.current_authority.key(),
+        staking_pool_key
+    );
+
+    Ok(())
+}
+
+// =============================================================================
+// Set Launch Boost
+// =============================================================================
+// Configures a time-decaying reward boost for early stakers. The multiplier
+// applies from now and decays linearly to 1x at end_time. Existing stakers
+// pick it up on their next stake change or poke.
+
+#[derive(Accounts)]
+pub struct SetLaunchBoost<'info> {
+    #[account(
+        constraint = admin.key() == staking_pool.admin @ StakingError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+}
+
+pub fn set_launch_boost(ctx: Context<SetLaunchBoost>, multiplier_bps: u16, end_time: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        multiplier_bps as u64 >= BOOST_BPS_DENOMINATOR && multiplier_bps <= MAX_BOOST_MULTIPLIER_BPS,
+        StakingError::InvalidBoostConfig
+    );
+    require!(end_time > now, StakingError::InvalidBoostConfig);
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    staking_pool.boost_multiplier_bps = multiplier_bps;
+    staking_pool.boost_start_time = now;
+    staking_pool.boost_end_time = end_time;
+
+    msg!(
+        "Launch boost set: {} bps decaying to 1x at {}",
+        multiplier_bps,
+        end_time
+    );
+
+    Ok(())
+}
+
+// =============================================================================
+// Set Distributor
+// =============================================================================
+// Lets a hot bot wallet call the distribute instructions while the admin
+// (e.g. a cold multisig) keeps pause and configuration control. The
+// distributor can only move rewards from its own source account into the
+// pool. Pubkey::default() removes it.
+
+#[derive(Accounts)]
+pub struct SetDistributor<'info> {
+    #[account(
+        constraint = admin.key() == staking_pool.admin @ StakingError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+}
+
+pub fn set_distributor(ctx: Context<SetDistributor>, distributor: Pubkey) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let old_distributor = staking_pool.distributor;
+    staking_pool.distributor = distributor;
+
+    msg!(
+        "Distributor updated: {} -> {}",
+        old_distributor,
+        distributor
+    );
+
+    Ok(())
+}
+
+// =============================================================================
+// Set Unstake Cooldown
+// =============================================================================
+// Sets how long stakers must wait between request_unstake and unstake.
+// Requests already made keep the availability time computed when they were
+// made.
+
+#[derive(Accounts)]
+pub struct SetUnstakeCooldown<'info> {
+    #[account(
+        constraint = admin.key() == staking_pool.admin @ StakingError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+}
+
+pub fn set_unstake_cooldown(ctx: Context<SetUnstakeCooldown>, cooldown_seconds: i64) -> Result<()> {
+    require!(
+        (0..=MAX_UNSTAKE_COOLDOWN_SECONDS).contains(&cooldown_seconds),
+        StakingError::InvalidUnstakeCooldown
+    );
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let old_cooldown = staking_pool.unstake_cooldown_seconds;
+    staking_pool.unstake_cooldown_seconds = cooldown_seconds;
+
+    msg!(
+        "Unstake cooldown updated: {}s -> {}s",
+        old_cooldown,
+        cooldown_seconds
+    );
+
+    Ok(())
+}
+
+// =============================================================================
+// Set Min Stake Duration For Rewards
+// =============================================================================
+// Sets how long a position must go without a stake change before the rewards
+// it accrues can be kept. Rewards accrued inside the window are forfeited to
+// the other stakers (via reward_per_token) if the position stakes, unstakes
+// or claims before the window ends.
+
+#[derive(Accounts)]
+pub struct SetMinStakeDuration<'info> {
+    #[account(
+        constraint = admin.key() == staking_pool.admin @ StakingError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+}
+
+pub fn set_min_stake_duration(ctx: Context<SetMinStakeDuration>, duration_seconds: i64) -> Result<()> {
+    require!(
+        (0..=MAX_MIN_STAKE_DURATION_FOR_REWARDS).contains(&duration_seconds),
+        StakingError::InvalidMinStakeDuration
+    );
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let old_duration = staking_pool.min_stake_duration_for_rewards;
+    staking_pool.min_stake_duration_for_rewards = duration_seconds;
+
+    msg!(
+        "Min stake duration for rewards updated: {}s -> {}s",
+        old_duration,
+        duration_seconds
+    );
+
+    Ok(())
+}
+
+// =============================================================================
+// Add Reward Token
+// =============================================================================
+// Registers a bonus reward token in the next free slot and creates its vault,
+// owned by the staking pool PDA. Reward index 0 stays the primary reward.
+
+#[derive(Accounts)]
+pub struct AddRewardToken<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == staking_pool.admin @ StakingError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Bonus reward token mint
+    pub reward_mint: Account<'info, Mint>,
+
+    /// Vault for the bonus reward, owned by the staking pool PDA
+    #[account(
+        init,
+        payer = admin,
+        seeds = [REWARD_VAULT_SEED, staking_pool.key().as_ref(), reward_mint.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = staking_pool
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn add_reward_token(ctx: Context<AddRewardToken>) -> Result<()> {
+    let reward_mint = ctx.accounts.reward_mint.key();
+    let staking_pool = &mut ctx.accounts.staking_pool;
+
+    require!(
+        reward_mint != staking_pool.reward_mint
+            && staking_pool.bonus_rewards.iter().all(|bonus| bonus.mint != reward_mint),
+        StakingError::DuplicateRewardMint
+    );
+
+    let slot = staking_pool
+        .bonus_rewards
+        .iter()
+        .position(|bonus| !bonus.is_configured())
+        .ok_or(StakingError::RewardTokenLimitReached)?;
+
+    staking_pool.bonus_rewards[slot] = BonusReward {
+        mint: reward_mint,
+        vault: ctx.accounts.reward_vault.key(),
+        ..BonusReward::default()
+    };
+
+    msg!(
+        "Bonus reward {} added at reward index {}",
+        reward_mint,
+        slot + 1
+    );
+
+    Ok(())
+}
+
+// =============================================================================
+// Recount Stakers
+// =============================================================================
+// One-off migration for pools created before stake counted returning
+// stakers: a full unstake followed by a new stake used to leave staker_count
+// too low. Pools created since then keep an accurate count on their own.
+//
+// Recomputes staker_count from the full set of Staker PDAs passed in
+// remaining_accounts. Duplicate accounts are rejected outright; completeness
+// is then verified by requiring the stakes to add up to total_staked, so an
+// omitted position is rejected too.
+
+#[derive(Accounts)]
+pub struct RecountStakers<'info> {
+    #[account(
+        constraint = admin.key() == staking_pool.admin @ StakingError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+}
+
+pub fn recount_stakers<'info>(ctx: Context<'_, '_, 'info, 'info, RecountStakers<'info>>) -> Result<()> {
+    let staking_pool_key = ctx.accounts.staking_pool.key();
+    let mut active_stakers: u64 = 0;
+    let mut counted_stake: u64 = 0;
+
+    // Otherwise a duplicate could stand in for an omitted position with the
+    // same stake and still pass the total_staked check
+    let mut keys: Vec<Pubkey> = ctx.remaining_accounts.iter().map(|a| a.key()).collect();
+    keys.sort_unstable();
+    require!(
+        keys.windows(2).all(|pair| pair[0] != pair[1]),
+        StakingError::DuplicateStakerAccount
+    );
+
+    for account_info in ctx.remaining_accounts.iter() {
+        // Checks program ownership and the Staker discriminator
+        let staker: Account<Staker> = Account::try_from(account_info)?;
+        require!(staker.pool == staking_pool_key, StakingError::InvalidPDA);
+
+        let expected = Pubkey::create_program_address(
+            &[
+                STAKER_SEED,
+                staking_pool_key.as_ref(),
+                staker.owner.as_ref(),
+                &[staker.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| StakingError::InvalidPDA)?;
+        require!(account_info.key() == expected, StakingError::InvalidPDA);
+
+        if staker.staked_amount > 0 {
+            active_stakers = active_stakers
+                .checked_add(1)
+                .ok_or(StakingError::MathOverflow)?;
+            counted_stake = counted_stake
+                .checked_add(staker.staked_amount)
+                .ok_or(StakingError::MathOverflow)?;
+        }
+    }
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    require!(
+        counted_stake == staking_pool.total_staked,
+        StakingError::StakerSetMismatch
+    );
+
+    let old_count = staking_pool.staker_count;
+    staking_pool.staker_count = active_stakers;
+
+    msg!(
+        "Staker count recounted: {} -> {}",
+        old_count,
+        active_stakers
+    );
+
+    Ok(())
+}
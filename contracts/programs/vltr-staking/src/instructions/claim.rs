@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
-use crate::constants::{STAKER_SEED, STAKING_POOL_SEED};
+use crate::constants::{
+    MAX_STAKE_AMOUNT, MIN_STAKE_AMOUNT, REWARD_VAULT_SEED, STAKER_SEED, STAKE_VAULT_SEED,
+    STAKING_POOL_SEED,
+};
 use crate::error::StakingError;
 use crate::state::{Staker, StakingPool};
 
@@ -63,45 +67,391 @@ pub struct Claim<'info> {
     )]
     pub reward_vault: Account<'info, TokenAccount>,
 
-    /// Authority that can sign for the reward vault transfers
-    /// Must be the owner of the reward_vault
-    pub reward_vault_authority: Signer<'info>,
+    /// Authority for the reward vault transfers - must be the vault owner
+    /// Either an external key that signs this transaction, or the
+    /// staking_pool PDA itself after set_reward_vault_authority
+    /// CHECK: Signer requirement enforced in pay_pending_rewards
+    pub reward_vault_authority: UncheckedAccount<'info>,
 
     pub token_program: Program<'info, Token>,
 }
 
 pub fn handler_claim(ctx: Context<Claim>) -> Result<()> {
-    let staking_pool = &ctx.accounts.staking_pool;
+    let accounts = ctx.accounts;
+    pay_pending_rewards(
+        &mut accounts.staking_pool,
+        &mut accounts.staker,
+        &accounts.reward_vault,
+        accounts.user_reward_account.to_account_info(),
+        accounts.reward_vault_authority.to_account_info(),
+        accounts.token_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
+
+/// Claim accumulated USDC rewards, creating the user's reward ATA if needed
+///
+/// Same as `claim`, but the user's associated token account for the reward
+/// mint is created (user pays rent) when it does not exist yet, so users who
+/// never held USDC can still claim.
+#[derive(Accounts)]
+pub struct ClaimWithInit<'info> {
+    /// User claiming their rewards (pays rent if the ATA is created)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Staking pool
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump,
+        constraint = !staking_pool.is_paused @ StakingError::PoolPaused
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// User's staker account
+    #[account(
+        mut,
+        seeds = [STAKER_SEED, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = staker.bump,
+        constraint = staker.owner == user.key() @ StakingError::InvalidAuthority
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Reward token mint (USDC)
+    #[account(
+        constraint = reward_mint.key() == staking_pool.reward_mint @ StakingError::InvalidRewardMint
+    )]
+    pub reward_mint: Account<'info, Mint>,
+
+    /// User's USDC associated token account (created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = reward_mint,
+        associated_token::authority = user
+    )]
+    pub user_reward_account: Account<'info, TokenAccount>,
+
+    /// Pool's reward vault (staking_rewards_vault from main pool)
+    #[account(
+        mut,
+        constraint = reward_vault.key() == staking_pool.reward_vault @ StakingError::InvalidPDA,
+        token::mint = reward_mint,
+        constraint = reward_vault.owner == reward_vault_authority.key() @ StakingError::InvalidTokenAccountOwner
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// Authority for the reward vault transfers - must be the vault owner
+    /// Either an external key that signs this transaction, or the
+    /// staking_pool PDA itself after set_reward_vault_authority
+    /// CHECK: Signer requirement enforced in pay_pending_rewards
+    pub reward_vault_authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler_claim_with_init(ctx: Context<ClaimWithInit>) -> Result<()> {
+    let accounts = ctx.accounts;
+    pay_pending_rewards(
+        &mut accounts.staking_pool,
+        &mut accounts.staker,
+        &accounts.reward_vault,
+        accounts.user_reward_account.to_account_info(),
+        accounts.reward_vault_authority.to_account_info(),
+        accounts.token_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
+
+/// Claim rewards and stake them in the same transaction
+///
+/// Only available when the reward mint is the VLTR mint. Pending rewards are
+/// moved from the reward vault into the stake vault and added to the caller's
+/// stake, so compounding needs no separate claim, swap and stake. The amount
+/// must be within the same bounds as `stake`. Unlike a stake, compounding
+/// does not restart the minimum stake duration or cancel a pending unstake
+/// request.
+#[derive(Accounts)]
+pub struct ClaimAndRestake<'info> {
+    /// User compounding their rewards
+    pub user: Signer<'info>,
+
+    /// Staking pool
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump,
+        constraint = !staking_pool.is_paused @ StakingError::PoolPaused,
+        constraint = staking_pool.reward_mint == staking_pool.vltr_mint @ StakingError::InvalidRewardMint
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// User's staker account
+    #[account(
+        mut,
+        seeds = [STAKER_SEED, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = staker.bump,
+        constraint = staker.owner == user.key() @ StakingError::InvalidAuthority
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// VLTR token mint (also the reward mint)
+    #[account(
+        constraint = vltr_mint.key() == staking_pool.vltr_mint @ StakingError::InvalidVltrMint
+    )]
+    pub vltr_mint: Account<'info, Mint>,
+
+    /// Pool's reward vault
+    #[account(
+        mut,
+        constraint = reward_vault.key() == staking_pool.reward_vault @ StakingError::InvalidPDA,
+        token::mint = vltr_mint,
+        constraint = reward_vault.owner == reward_vault_authority.key() @ StakingError::InvalidTokenAccountOwner
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// Authority for the reward vault transfers - must be the vault owner
+    /// CHECK: Signer requirement enforced in pay_pending_rewards
+    pub reward_vault_authority: UncheckedAccount<'info>,
+
+    /// Pool's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, staking_pool.key().as_ref()],
+        bump = staking_pool.stake_vault_bump,
+        token::mint = vltr_mint,
+        token::authority = staking_pool
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler_claim_and_restake(ctx: Context<ClaimAndRestake>) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    // Compounding tops up an existing position; a fully exited staker claims
+    require!(accounts.staker.staked_amount > 0, StakingError::InsufficientStake);
+
+    let restaked = pay_pending_rewards(
+        &mut accounts.staking_pool,
+        &mut accounts.staker,
+        &accounts.reward_vault,
+        accounts.stake_vault.to_account_info(),
+        accounts.reward_vault_authority.to_account_info(),
+        accounts.token_program.to_account_info(),
+    )?;
+
+    // Same bounds as stake; smaller rewards can be claimed instead
+    require!(restaked >= MIN_STAKE_AMOUNT, StakingError::BelowMinimumStake);
+    require!(restaked <= MAX_STAKE_AMOUNT, StakingError::ExceedsMaximumStake);
+
+    let staking_pool = &mut accounts.staking_pool;
+    let staker = &mut accounts.staker;
+
+    // The claim moved reward_debt to the current reward_per_token, so
+    // record_restake banks nothing and the rewards cannot be claimed twice
+    staker.record_restake(restaked, staking_pool.reward_per_token)?;
+    staking_pool.reweight_staker(staker, Clock::get()?.unix_timestamp)?;
+
+    staking_pool.total_staked = staking_pool
+        .total_staked
+        .checked_add(restaked)
+        .ok_or(StakingError::MathOverflow)?;
+
+    msg!(
+        "Restaked {} VLTR rewards. User total: {}, Pool total: {}",
+        restaked,
+        staker.staked_amount,
+        staking_pool.total_staked
+    );
+
+    Ok(())
+}
+
+/// Claim accumulated rewards of a bonus reward token
+///
+/// Bonus vaults are owned by the staking pool PDA, which signs the transfer.
+///
+/// # Arguments
+/// * `ctx` - The context containing all accounts
+/// * `reward_index` - Bonus reward index (1..MAX_REWARD_TOKENS)
+///
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct ClaimReward<'info> {
+    /// User claiming their rewards
+    pub user: Signer<'info>,
+
+    /// Staking pool
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump,
+        constraint = !staking_pool.is_paused @ StakingError::PoolPaused
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// User's staker account
+    #[account(
+        mut,
+        seeds = [STAKER_SEED, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = staker.bump,
+        constraint = staker.owner == user.key() @ StakingError::InvalidAuthority
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Bonus reward token mint
+    #[account(
+        constraint = staking_pool.bonus_slot(reward_index)
+            .is_ok_and(|slot| staking_pool.bonus_rewards[slot].mint == reward_mint.key())
+            @ StakingError::InvalidRewardIndex
+    )]
+    pub reward_mint: Account<'info, Mint>,
+
+    /// User's token account for the bonus reward
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = user
+    )]
+    pub user_reward_account: Account<'info, TokenAccount>,
+
+    /// Bonus reward vault
+    #[account(
+        mut,
+        seeds = [REWARD_VAULT_SEED, staking_pool.key().as_ref(), reward_mint.key().as_ref()],
+        bump,
+        token::mint = reward_mint
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler_claim_reward(ctx: Context<ClaimReward>, reward_index: u8) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
     let staker = &mut ctx.accounts.staker;
+    let slot = staking_pool.bonus_slot(reward_index)?;
 
-    // Calculate pending rewards
-    let pending_rewards = staker.calculate_pending_rewards(staking_pool.reward_per_token)?;
+    // Bring streamed rewards up to date before anything reads reward_per_token
+    staking_pool.accrue_streamed_rewards(Clock::get()?.unix_timestamp)?;
 
-    // Ensure there are rewards to claim
-    require!(pending_rewards > 0, StakingError::NoRewardsToClaim);
+    // Rewards accrued too soon after the last stake change are forfeited
+    staking_pool.forfeit_ineligible_rewards(staker, Clock::get()?.unix_timestamp)?;
 
-    // Check reward vault has enough balance
+    let bonus_rpt = staking_pool.bonus_rewards[slot].reward_per_token;
+    let pending_rewards = staker.bonus_claimable(slot, bonus_rpt)?;
+    require!(pending_rewards > 0, StakingError::NoRewardsToClaim);
     require!(
         ctx.accounts.reward_vault.amount >= pending_rewards,
         StakingError::InsufficientRewardBalance
     );
 
-    // Transfer USDC from reward vault to user
-    // The reward_vault_authority signs this transfer
+    let pool_seeds = &[
+        STAKING_POOL_SEED,
+        staking_pool.vltr_mint.as_ref(),
+        &[staking_pool.bump],
+    ];
     token::transfer(
-        CpiContext::new(
+        CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             Transfer {
                 from: ctx.accounts.reward_vault.to_account_info(),
                 to: ctx.accounts.user_reward_account.to_account_info(),
-                authority: ctx.accounts.reward_vault_authority.to_account_info(),
+                authority: staking_pool.to_account_info(),
             },
+            &[&pool_seeds[..]],
         ),
         pending_rewards,
     )?;
 
+    staker.record_bonus_claim(slot, pending_rewards, bonus_rpt)?;
+    let bonus = &mut staking_pool.bonus_rewards[slot];
+    bonus.total_owed = bonus.total_owed.saturating_sub(pending_rewards);
+
+    msg!(
+        "Claimed {} of bonus reward {}. Total claimed: {}",
+        pending_rewards,
+        reward_index,
+        staker.bonus_rewards_claimed[slot]
+    );
+
+    Ok(())
+}
+
+/// Shared claim logic: pay pending rewards from the reward vault to
+/// `destination` and return the amount paid
+fn pay_pending_rewards<'info>(
+    staking_pool: &mut Account<'info, StakingPool>,
+    staker: &mut Account<'info, Staker>,
+    reward_vault: &Account<'info, TokenAccount>,
+    destination: AccountInfo<'info>,
+    reward_vault_authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+) -> Result<u64> {
+    // Bring streamed rewards up to date before anything reads reward_per_token
+    staking_pool.accrue_streamed_rewards(Clock::get()?.unix_timestamp)?;
+
+    // Rewards accrued too soon after the last stake change are forfeited
+    staking_pool.forfeit_ineligible_rewards(staker, Clock::get()?.unix_timestamp)?;
+
+    // Calculate pending rewards (banked + fresh accrual)
+    let pending_rewards = staker.total_claimable(staking_pool.reward_per_token)?;
+
+    // Ensure there are rewards to claim
+    require!(pending_rewards > 0, StakingError::NoRewardsToClaim);
+
+    // Check reward vault has enough balance
+    require!(
+        reward_vault.amount >= pending_rewards,
+        StakingError::InsufficientRewardBalance
+    );
+
+    // Transfer USDC from reward vault to user
+    if reward_vault.owner == staking_pool.key() {
+        // PDA-owned vault: the staking pool signs
+        let pool_seeds = &[
+            STAKING_POOL_SEED,
+            staking_pool.vltr_mint.as_ref(),
+            &[staking_pool.bump],
+        ];
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program,
+                Transfer {
+                    from: reward_vault.to_account_info(),
+                    to: destination,
+                    authority: staking_pool.to_account_info(),
+                },
+                &[&pool_seeds[..]],
+            ),
+            pending_rewards,
+        )?;
+    } else {
+        // Externally-owned vault: the owner must co-sign
+        require!(reward_vault_authority.is_signer, StakingError::InvalidAuthority);
+        token::transfer(
+            CpiContext::new(
+                token_program,
+                Transfer {
+                    from: reward_vault.to_account_info(),
+                    to: destination,
+                    authority: reward_vault_authority,
+                },
+            ),
+            pending_rewards,
+        )?;
+    }
+
     // Update staker's reward tracking
     staker.record_claim(pending_rewards, staking_pool.reward_per_token)?;
+    staking_pool.record_rewards_paid(pending_rewards);
 
     msg!(
         "Claimed {} USDC rewards. Total claimed: {}",
@@ -109,5 +459,5 @@ pub fn handler_claim(ctx: Context<Claim>) -> Result<()> {
         staker.rewards_claimed
     );
 
-    Ok(())
+    Ok(pending_rewards)
 }
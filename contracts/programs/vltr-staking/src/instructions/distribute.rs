@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
-use crate::constants::{MIN_DISTRIBUTE_AMOUNT, STAKING_POOL_SEED};
+use crate::constants::{
+    MAX_BATCH_DISTRIBUTIONS, MAX_REWARD_PER_DISTRIBUTION, REWARD_VAULT_SEED, STAKING_POOL_SEED,
+};
 use crate::error::StakingError;
 use crate::state::StakingPool;
 
@@ -16,12 +18,14 @@ use crate::state::StakingPool;
 /// * `amount` - Amount of USDC to distribute
 ///
 /// # Flow
-/// 1. Transfer USDC from source to reward vault
-/// 2. Update pool's reward_per_token
+/// 1. Fail with ExceedsMaxDistribution above MAX_REWARD_PER_DISTRIBUTION, or
+///    NoStakersToDistribute if there is no effective stake
+/// 2. Transfer USDC from source to reward vault
+/// 3. Update pool's reward_per_token
 ///
 #[derive(Accounts)]
 pub struct Distribute<'info> {
-    /// Authority distributing rewards (admin or bot)
+    /// Authority distributing rewards (admin or distributor)
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -30,7 +34,7 @@ pub struct Distribute<'info> {
         mut,
         seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
         bump = staking_pool.bump,
-        constraint = staking_pool.admin == authority.key() @ StakingError::Unauthorized
+        constraint = staking_pool.can_distribute(&authority.key()) @ StakingError::Unauthorized
     )]
     pub staking_pool: Account<'info, StakingPool>,
 
@@ -64,18 +68,24 @@ pub fn handler_distribute(ctx: Context<Distribute>, amount: u64) -> Result<()> {
     // Validate amount
     require!(amount > 0, StakingError::InvalidAmount);
     require!(
-        amount >= MIN_DISTRIBUTE_AMOUNT,
-        StakingError::InvalidAmount
+        amount <= MAX_REWARD_PER_DISTRIBUTION,
+        StakingError::ExceedsMaxDistribution
     );
 
     let staking_pool = &mut ctx.accounts.staking_pool;
 
-    // If no stakers, we can't distribute
-    // The rewards should stay in source or be returned
-    if staking_pool.total_staked == 0 {
-        msg!("No stakers - cannot distribute rewards. Skipping.");
-        return Ok(());
-    }
+    require!(
+        amount >= staking_pool.min_distribute,
+        StakingError::InvalidAmount
+    );
+
+    // If no stakers, we can't distribute. Fail before any transfer so the
+    // rewards stay in source and the caller knows nothing was distributed.
+    // reward_per_token divides by effective stake, so that is what must be set
+    require!(
+        staking_pool.total_effective_stake > 0,
+        StakingError::NoStakersToDistribute
+    );
 
     // Transfer USDC from source to reward vault
     token::transfer(
@@ -91,6 +101,7 @@ pub fn handler_distribute(ctx: Context<Distribute>, amount: u64) -> Result<()> {
     )?;
 
     // Update reward_per_token
+    staking_pool.accrue_streamed_rewards(Clock::get()?.unix_timestamp)?;
     staking_pool.update_reward_per_token(amount)?;
 
     msg!(
@@ -103,3 +114,282 @@ pub fn handler_distribute(ctx: Context<Distribute>, amount: u64) -> Result<()> {
 
     Ok(())
 }
+
+/// Stream USDC rewards to stakers over `duration_seconds`
+///
+/// Uses the same accounts as `distribute`. The full amount moves into the
+/// reward vault now, but reward_per_token advances linearly until the stream
+/// ends, so stake added right before the call earns only its share of what
+/// streams while it is staked. Calling again mid-stream rolls the unstreamed
+/// remainder into the new stream.
+///
+/// # Arguments
+/// * `ctx` - The context containing all accounts
+/// * `amount` - Amount of USDC to stream
+/// * `duration_seconds` - Stream length (max MAX_REWARD_DURATION_SECONDS)
+///
+pub fn handler_distribute_streamed(ctx: Context<Distribute>, amount: u64, duration_seconds: i64) -> Result<()> {
+    require!(amount > 0, StakingError::InvalidAmount);
+    require!(
+        amount <= MAX_REWARD_PER_DISTRIBUTION,
+        StakingError::ExceedsMaxDistribution
+    );
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+
+    require!(
+        amount >= staking_pool.min_distribute,
+        StakingError::InvalidAmount
+    );
+
+    require!(
+        staking_pool.total_effective_stake > 0,
+        StakingError::NoStakersToDistribute
+    );
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_source.to_account_info(),
+                to: ctx.accounts.reward_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    staking_pool.accrue_streamed_rewards(now)?;
+    staking_pool.start_reward_stream(amount, duration_seconds, now)?;
+
+    msg!(
+        "Streaming {} USDC until {}. reward_rate: {}",
+        amount,
+        staking_pool.reward_period_finish,
+        staking_pool.reward_rate
+    );
+
+    Ok(())
+}
+
+/// Distribute a bonus reward token to stakers
+///
+/// Same as `distribute`, for a bonus reward registered with
+/// add_reward_token. Reward index 0 is the primary reward; use `distribute`.
+///
+/// # Arguments
+/// * `ctx` - The context containing all accounts
+/// * `reward_index` - Bonus reward index (1..MAX_REWARD_TOKENS)
+/// * `amount` - Amount of the bonus token to distribute
+///
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct DistributeReward<'info> {
+    /// Authority distributing rewards (admin or distributor)
+    pub authority: Signer<'info>,
+
+    /// Staking pool
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump,
+        constraint = staking_pool.can_distribute(&authority.key()) @ StakingError::Unauthorized
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Bonus reward token mint
+    #[account(
+        constraint = staking_pool.bonus_slot(reward_index)
+            .is_ok_and(|slot| staking_pool.bonus_rewards[slot].mint == reward_mint.key())
+            @ StakingError::InvalidRewardIndex
+    )]
+    pub reward_mint: Account<'info, Mint>,
+
+    /// Source of rewards, owned by authority
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        constraint = reward_source.owner == authority.key() @ StakingError::InvalidTokenAccountOwner
+    )]
+    pub reward_source: Account<'info, TokenAccount>,
+
+    /// Bonus reward vault
+    #[account(
+        mut,
+        seeds = [REWARD_VAULT_SEED, staking_pool.key().as_ref(), reward_mint.key().as_ref()],
+        bump,
+        token::mint = reward_mint
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler_distribute_reward(ctx: Context<DistributeReward>, reward_index: u8, amount: u64) -> Result<()> {
+    require!(amount > 0, StakingError::InvalidAmount);
+    require!(
+        amount <= MAX_REWARD_PER_DISTRIBUTION,
+        StakingError::ExceedsMaxDistribution
+    );
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let slot = staking_pool.bonus_slot(reward_index)?;
+
+    // Same as the primary reward: nothing to distribute to
+    require!(
+        staking_pool.total_effective_stake > 0,
+        StakingError::NoStakersToDistribute
+    );
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_source.to_account_info(),
+                to: ctx.accounts.reward_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    staking_pool.distribute_bonus_reward(slot, amount)?;
+
+    msg!(
+        "Distributed {} of bonus reward {}. Total distributed: {}, reward_per_token: {}",
+        amount,
+        reward_index,
+        staking_pool.bonus_rewards[slot].total_distributed,
+        staking_pool.bonus_rewards[slot].reward_per_token
+    );
+
+    Ok(())
+}
+
+/// Distribute USDC rewards to several staking pools in one transaction
+///
+/// Same as calling `distribute` once per pool, from a single reward source.
+/// Every pool must use `reward_mint` and accept `authority` as admin or
+/// distributor; if any entry fails, the whole batch reverts.
+///
+/// # Arguments
+/// * `ctx` - The context containing all accounts
+/// * `amounts` - Amount of USDC for each pool, in remaining_accounts order
+///
+/// # Remaining Accounts
+/// For each amount, in order:
+/// 1. Staking pool (writable)
+/// 2. That pool's reward vault (writable)
+///
+#[derive(Accounts)]
+pub struct DistributeBatch<'info> {
+    /// Authority distributing rewards; admin or distributor of every pool
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Reward token mint (USDC), shared by every pool in the batch
+    pub reward_mint: Account<'info, Mint>,
+
+    /// Source of rewards, owned by authority
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        constraint = reward_source.owner == authority.key() @ StakingError::InvalidTokenAccountOwner
+    )]
+    pub reward_source: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler_distribute_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeBatch<'info>>,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    require!(
+        !amounts.is_empty() && amounts.len() <= MAX_BATCH_DISTRIBUTIONS,
+        StakingError::InvalidBatch
+    );
+    require!(
+        ctx.remaining_accounts.len() == amounts.len() * 2,
+        StakingError::InvalidBatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    for (accounts, &amount) in ctx.remaining_accounts.chunks_exact(2).zip(amounts.iter()) {
+        let (pool_info, vault_info) = (&accounts[0], &accounts[1]);
+        require!(
+            pool_info.is_writable && vault_info.is_writable,
+            StakingError::InvalidBatch
+        );
+
+        // Checks program ownership and the StakingPool discriminator. Each
+        // pool is written back before the next is loaded, so a pool listed
+        // twice sees its own earlier update.
+        let mut staking_pool: Account<StakingPool> = Account::try_from(pool_info)?;
+
+        let expected = Pubkey::create_program_address(
+            &[
+                STAKING_POOL_SEED,
+                staking_pool.vltr_mint.as_ref(),
+                &[staking_pool.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| StakingError::InvalidPDA)?;
+        require!(pool_info.key() == expected, StakingError::InvalidPDA);
+        require!(
+            staking_pool.can_distribute(&ctx.accounts.authority.key()),
+            StakingError::Unauthorized
+        );
+        require!(
+            staking_pool.reward_mint == ctx.accounts.reward_mint.key(),
+            StakingError::InvalidRewardMint
+        );
+        require!(
+            vault_info.key() == staking_pool.reward_vault,
+            StakingError::InvalidPDA
+        );
+
+        require!(amount > 0, StakingError::InvalidAmount);
+        require!(
+            amount <= MAX_REWARD_PER_DISTRIBUTION,
+            StakingError::ExceedsMaxDistribution
+        );
+        require!(
+            amount >= staking_pool.min_distribute,
+            StakingError::InvalidAmount
+        );
+
+        require!(
+            staking_pool.total_effective_stake > 0,
+            StakingError::NoStakersToDistribute
+        );
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_source.to_account_info(),
+                    to: vault_info.clone(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        staking_pool.accrue_streamed_rewards(now)?;
+        staking_pool.update_reward_per_token(amount)?;
+        staking_pool.exit(ctx.program_id)?;
+
+        msg!(
+            "Distributed {} USDC to {}. reward_per_token: {}",
+            amount,
+            pool_info.key(),
+            staking_pool.reward_per_token
+        );
+    }
+
+    Ok(())
+}
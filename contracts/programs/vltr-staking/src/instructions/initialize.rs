@@ -75,10 +75,33 @@ pub fn handler_initialize(ctx: Context<Initialize>) -> Result<()> {
 
     // Initialize counters
     staking_pool.total_staked = 0;
+    staking_pool.total_effective_stake = 0;
     staking_pool.total_rewards_distributed = 0;
     staking_pool.reward_per_token = 0;
     staking_pool.last_distribution_time = Clock::get()?.unix_timestamp;
     staking_pool.staker_count = 0;
+    staking_pool.total_forfeited = 0;
+    staking_pool.total_rewards_owed = 0;
+
+    // Scale the minimum distribution to the reward mint
+    staking_pool.min_distribute =
+        StakingPool::min_distribute_for_decimals(ctx.accounts.reward_mint.decimals)?;
+
+    // No launch boost until configured
+    staking_pool.boost_multiplier_bps = 0;
+    staking_pool.boost_start_time = 0;
+    staking_pool.boost_end_time = 0;
+
+    // No unstake cooldown until configured
+    staking_pool.unstake_cooldown_seconds = 0;
+
+    // Rewards are eligible immediately until configured
+    staking_pool.min_stake_duration_for_rewards = 0;
+
+    // No reward stream running
+    staking_pool.reward_rate = 0;
+    staking_pool.reward_period_finish = 0;
+    staking_pool.reward_last_update_time = 0;
 
     // Not paused by default
     staking_pool.is_paused = false;
@@ -88,9 +111,10 @@ pub fn handler_initialize(ctx: Context<Initialize>) -> Result<()> {
     staking_pool.stake_vault_bump = ctx.bumps.stake_vault;
 
     msg!(
-        "Staking pool initialized: vltr_mint={}, reward_mint={}",
+        "Staking pool initialized: vltr_mint={}, reward_mint={}, min_distribute={}",
         staking_pool.vltr_mint,
-        staking_pool.reward_mint
+        staking_pool.reward_mint,
+        staking_pool.min_distribute
     );
 
     Ok(())
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{STAKER_SEED, STAKING_POOL_SEED};
+use crate::error::StakingError;
+use crate::state::{Staker, StakingPool};
+
+/// Lock a staking position for a fixed term in exchange for a reward multiplier
+///
+/// # Arguments
+/// * `ctx` - The context containing all accounts
+/// * `duration_seconds` - One of the LOCK_TIERS durations (30/90/180 days)
+///
+/// # Flow
+/// 1. Bring rewards up to date and bank them at the old weight
+/// 2. Set lock_until and the tier's multiplier
+/// 3. Re-weight the position
+///
+/// The whole position is locked, including stake added later. The
+/// multiplier stops applying at lock_until; a poke drops it back to 1x.
+#[derive(Accounts)]
+pub struct LockStake<'info> {
+    /// User locking their position
+    pub user: Signer<'info>,
+
+    /// Staking pool
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump,
+        constraint = !staking_pool.is_paused @ StakingError::PoolPaused
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// User's staker account
+    #[account(
+        mut,
+        seeds = [STAKER_SEED, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = staker.bump,
+        constraint = staker.owner == user.key() @ StakingError::InvalidAuthority
+    )]
+    pub staker: Account<'info, Staker>,
+}
+
+pub fn handler_lock_stake(ctx: Context<LockStake>, duration_seconds: i64) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let staker = &mut ctx.accounts.staker;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Bring streamed rewards up to date before anything reads reward_per_token
+    staking_pool.accrue_streamed_rewards(now)?;
+
+    // Rewards accrued too soon after the last stake change are forfeited
+    staking_pool.forfeit_ineligible_rewards(staker, now)?;
+
+    // Bank rewards earned at the old weight before it changes
+    staker.bank_pending_rewards(staking_pool.reward_per_token)?;
+
+    staker.apply_lock(now, duration_seconds)?;
+    staking_pool.reweight_staker(staker, now)?;
+
+    msg!(
+        "Locked {} VLTR until {} at {} bps. Effective stake: {}",
+        staker.staked_amount,
+        staker.lock_until,
+        staker.lock_boost_bps,
+        staker.effective_stake
+    );
+
+    Ok(())
+}
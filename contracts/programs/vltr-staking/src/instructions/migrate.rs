@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token::Mint;
+
+use crate::constants::{STAKER_SEED, STAKING_POOL_SEED};
+use crate::error::StakingError;
+use crate::state::{Staker, StakingPool};
+
+// =============================================================================
+// Account Migration
+// =============================================================================
+// Pools and positions created with the original layout are too small for the
+// current one and cannot be loaded by any other instruction. Migrating grows
+// them in place (the payer covers the extra rent) and fills in the effective
+// stake the reward math divides by. The pool must be migrated before its
+// stakers.
+
+/// Grow a legacy staking pool to the current layout (admin only)
+#[derive(Accounts)]
+pub struct MigrateStakingPool<'info> {
+    /// Pool admin, read from the legacy account; pays the extra rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Legacy staking pool
+    /// CHECK: Cannot be deserialized before migration; PDA and program
+    /// ownership are checked here, the layout in the handler
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, vltr_mint.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub staking_pool: UncheckedAccount<'info>,
+
+    /// VLTR token mint
+    pub vltr_mint: Account<'info, Mint>,
+
+    /// Reward token mint, for the decimals-aware minimum distribution
+    pub reward_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_migrate_staking_pool(ctx: Context<MigrateStakingPool>) -> Result<()> {
+    let pool_info = ctx.accounts.staking_pool.to_account_info();
+
+    {
+        let data = pool_info.try_borrow_data()?;
+        require!(
+            data.len() == StakingPool::LEGACY_SIZE,
+            StakingError::AlreadyMigrated
+        );
+        require!(
+            &data[..8] == StakingPool::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        // admin and reward_mint kept their original offsets
+        require!(
+            data[8..40] == ctx.accounts.admin.key().to_bytes(),
+            StakingError::Unauthorized
+        );
+        require!(
+            data[72..104] == ctx.accounts.reward_mint.key().to_bytes(),
+            StakingError::InvalidRewardMint
+        );
+    }
+
+    grow_account(
+        &pool_info,
+        StakingPool::SIZE,
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+    )?;
+
+    let mut staking_pool = StakingPool::try_deserialize(&mut &pool_info.try_borrow_data()?[..])?;
+    staking_pool.migrate_from_legacy(ctx.accounts.reward_mint.decimals)?;
+    staking_pool.try_serialize(&mut &mut pool_info.try_borrow_mut_data()?[..])?;
+
+    msg!(
+        "Staking pool {} migrated: total_effective_stake={}, min_distribute={}",
+        pool_info.key(),
+        staking_pool.total_effective_stake,
+        staking_pool.min_distribute
+    );
+
+    Ok(())
+}
+
+/// Grow a legacy staker position to the current layout
+///
+/// Permissionless: the result depends only on the position, so anyone can
+/// migrate any staker and pay the extra rent.
+#[derive(Accounts)]
+pub struct MigrateStaker<'info> {
+    /// Pays the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Staking pool, already migrated
+    #[account(
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Owner of the position
+    /// CHECK: Only used to derive the staker PDA
+    pub owner: UncheckedAccount<'info>,
+
+    /// Legacy staker position
+    /// CHECK: Cannot be deserialized before migration; PDA and program
+    /// ownership are checked here, the layout in the handler
+    #[account(
+        mut,
+        seeds = [STAKER_SEED, staking_pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub staker: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_migrate_staker(ctx: Context<MigrateStaker>) -> Result<()> {
+    let staker_info = ctx.accounts.staker.to_account_info();
+
+    {
+        let data = staker_info.try_borrow_data()?;
+        require!(
+            data.len() == Staker::LEGACY_SIZE,
+            StakingError::AlreadyMigrated
+        );
+        require!(
+            &data[..8] == Staker::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+    }
+
+    grow_account(
+        &staker_info,
+        Staker::SIZE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    let mut staker = Staker::try_deserialize(&mut &staker_info.try_borrow_data()?[..])?;
+    staker.migrate_from_legacy();
+    staker.try_serialize(&mut &mut staker_info.try_borrow_mut_data()?[..])?;
+
+    msg!(
+        "Staker {} migrated: effective stake {}",
+        staker.owner,
+        staker.effective_stake
+    );
+
+    Ok(())
+}
+
+/// Top up rent for `new_len` bytes and zero-extend the account to it
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    new_len: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let top_up = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    account.resize(new_len)?;
+
+    Ok(())
+}
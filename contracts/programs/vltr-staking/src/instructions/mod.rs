@@ -6,12 +6,20 @@ pub mod admin;
 pub mod claim;
 pub mod distribute;
 pub mod initialize;
+pub mod lock;
+pub mod migrate;
+pub mod poke;
 pub mod stake;
 pub mod unstake;
+pub mod views;
 
 pub use admin::*;
 pub use claim::*;
 pub use distribute::*;
 pub use initialize::*;
+pub use lock::*;
+pub use migrate::*;
+pub use poke::*;
 pub use stake::*;
 pub use unstake::*;
+pub use views::*;
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{STAKER_SEED, STAKING_POOL_SEED};
+use crate::error::StakingError;
+use crate::state::{Staker, StakingPool};
+
+/// Refresh a staker's effective stake to the current launch boost
+///
+/// A staker's boost is only re-evaluated when their stake changes. Poking
+/// banks their pending rewards and re-weights them at the current (decayed)
+/// multiplier, so an early staker cannot keep a full boost after it ends,
+/// and a lock multiplier is dropped once lock_until has passed.
+/// Permissionless: anyone can poke any staker, so a staker still inside the
+/// minimum stake duration cannot be poked (that would forfeit their rewards).
+#[derive(Accounts)]
+pub struct Poke<'info> {
+    /// Staking pool
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump,
+        constraint = !staking_pool.is_paused @ StakingError::PoolPaused
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Staker position to refresh (any owner)
+    #[account(
+        mut,
+        seeds = [STAKER_SEED, staking_pool.key().as_ref(), staker.owner.as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+}
+
+pub fn handler_poke(ctx: Context<Poke>) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let staker = &mut ctx.accounts.staker;
+
+    let old_effective = staker.effective_stake;
+
+    require!(
+        staker.is_reward_eligible(
+            Clock::get()?.unix_timestamp,
+            staking_pool.min_stake_duration_for_rewards
+        ),
+        StakingError::RewardsNotYetEligible
+    );
+
+    // Bring streamed rewards up to date before anything reads reward_per_token
+    staking_pool.accrue_streamed_rewards(Clock::get()?.unix_timestamp)?;
+
+    // Bank rewards earned at the old weight before it changes
+    staker.bank_pending_rewards(staking_pool.reward_per_token)?;
+    staking_pool.reweight_staker(staker, Clock::get()?.unix_timestamp)?;
+
+    msg!(
+        "Poked staker {}: effective stake {} -> {}, pool effective stake: {}",
+        staker.owner,
+        old_effective,
+        staker.effective_stake,
+        staking_pool.total_effective_stake
+    );
+
+    Ok(())
+}
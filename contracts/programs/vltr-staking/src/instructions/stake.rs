@@ -79,16 +79,16 @@ pub fn handler_stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
     let staking_pool = &mut ctx.accounts.staking_pool;
     let staker = &mut ctx.accounts.staker;
 
-    // Check if this is a new staker
-    let is_new_staker = staker.staked_amount == 0 && staker.pool == Pubkey::default();
-
-    // Initialize staker if new
-    if is_new_staker {
+    // Initialize staker if the account was just created
+    if staker.pool == Pubkey::default() {
         staker.pool = staking_pool.key();
         staker.owner = ctx.accounts.user.key();
         staker.bump = ctx.bumps.staker;
+    }
 
-        // Increment staker count
+    // Count every position that goes from empty to staked, including a
+    // returning staker, to mirror the decrement on a full unstake
+    if staker.staked_amount == 0 {
         staking_pool.staker_count = staking_pool
             .staker_count
             .checked_add(1)
@@ -108,9 +108,18 @@ pub fn handler_stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
         amount,
     )?;
 
+    // Bring streamed rewards up to date before anything reads reward_per_token
+    staking_pool.accrue_streamed_rewards(Clock::get()?.unix_timestamp)?;
+
+    // Rewards accrued too soon after the last stake change are forfeited
+    staking_pool.forfeit_ineligible_rewards(staker, Clock::get()?.unix_timestamp)?;
+
     // Update staker position (handles reward debt)
     staker.record_stake(amount, staking_pool.reward_per_token)?;
 
+    // Weight the new balance by the current launch boost
+    staking_pool.reweight_staker(staker, Clock::get()?.unix_timestamp)?;
+
     // Update pool total staked
     staking_pool.total_staked = staking_pool
         .total_staked
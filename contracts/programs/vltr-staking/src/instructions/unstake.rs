@@ -5,14 +5,60 @@ use crate::constants::{STAKER_SEED, STAKING_POOL_SEED, STAKE_VAULT_SEED};
 use crate::error::StakingError;
 use crate::state::{Staker, StakingPool};
 
-/// Unstake VLTR tokens (no cooldown)
+/// Start the unstake cooldown for the caller's position
+///
+/// Only needed when the pool has an unstake cooldown configured. The request
+/// is cleared by the next stake or unstake.
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    /// User requesting to unstake
+    pub user: Signer<'info>,
+
+    /// Staking pool
+    #[account(
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump,
+        constraint = !staking_pool.is_paused @ StakingError::PoolPaused
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// User's staker account
+    #[account(
+        mut,
+        seeds = [STAKER_SEED, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = staker.bump,
+        constraint = staker.owner == user.key() @ StakingError::InvalidAuthority
+    )]
+    pub staker: Account<'info, Staker>,
+}
+
+pub fn handler_request_unstake(ctx: Context<RequestUnstake>) -> Result<()> {
+    let cooldown_seconds = ctx.accounts.staking_pool.unstake_cooldown_seconds;
+    let staker = &mut ctx.accounts.staker;
+
+    staker.request_unstake(Clock::get()?.unix_timestamp, cooldown_seconds)?;
+
+    msg!(
+        "Unstake requested by {}: available at {}",
+        staker.owner,
+        staker.unstake_available_at
+    );
+
+    Ok(())
+}
+
+/// Unstake VLTR tokens
+///
+/// If the pool has an unstake cooldown, the user must have called
+/// request_unstake at least `unstake_cooldown_seconds` earlier. Locked
+/// positions cannot be unstaked before lock_until.
 ///
 /// # Arguments
 /// * `ctx` - The context containing all accounts
 /// * `amount` - Amount of VLTR tokens to unstake
 ///
 /// # Flow
-/// 1. Validate amount and user has enough staked
+/// 1. Validate amount, cooldown and user has enough staked
 /// 2. Transfer VLTR from stake vault back to user
 /// 3. Update staker position
 /// 4. Update pool totals
@@ -75,6 +121,11 @@ pub fn handler_unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
         ctx.accounts.staker.staked_amount >= amount,
         StakingError::InsufficientStake
     );
+    ctx.accounts.staker.check_unlocked(Clock::get()?.unix_timestamp)?;
+    ctx.accounts.staker.check_unstake_cooldown(
+        Clock::get()?.unix_timestamp,
+        ctx.accounts.staking_pool.unstake_cooldown_seconds,
+    )?;
 
     let staking_pool = &mut ctx.accounts.staking_pool;
     let staker = &mut ctx.accounts.staker;
@@ -102,21 +153,28 @@ pub fn handler_unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
         amount,
     )?;
 
+    // Bring streamed rewards up to date before anything reads reward_per_token
+    staking_pool.accrue_streamed_rewards(Clock::get()?.unix_timestamp)?;
+
+    // Rewards accrued too soon after the last stake change are forfeited
+    staking_pool.forfeit_ineligible_rewards(staker, Clock::get()?.unix_timestamp)?;
+
     // Update staker position (handles reward debt)
     staker.record_unstake(amount, staking_pool.reward_per_token)?;
 
+    // Re-weight the remaining balance (drops to 0 on a full exit)
+    staking_pool.reweight_staker(staker, Clock::get()?.unix_timestamp)?;
+
     // Update pool total staked
     staking_pool.total_staked = staking_pool
         .total_staked
         .checked_sub(amount)
         .ok_or(StakingError::MathUnderflow)?;
 
-    // Decrement staker count if fully unstaked
+    // Decrement staker count if fully unstaked. staker_count is bookkeeping
+    // only, so a drifted count must never block an exit
     if staker.staked_amount == 0 {
-        staking_pool.staker_count = staking_pool
-            .staker_count
-            .checked_sub(1)
-            .ok_or(StakingError::MathUnderflow)?;
+        staking_pool.staker_count = staking_pool.staker_count.saturating_sub(1);
     }
 
     msg!(
@@ -128,3 +186,129 @@ pub fn handler_unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
 
     Ok(())
 }
+
+/// Withdraw the caller's whole stake while the pool is paused
+///
+/// Escape hatch for stakers if the admin pauses the pool and never resumes
+/// it. Returns the full VLTR principal, ignoring any lock or unstake
+/// cooldown, and forfeits every pending reward (primary and bonus) to the
+/// remaining stakers.
+///
+/// # Arguments
+/// * `ctx` - The context containing all accounts (same as `unstake`)
+///
+/// # Flow
+/// 1. Transfer all staked VLTR from stake vault back to user
+/// 2. Forfeit pending rewards and clear the lock
+/// 3. Update staker position and pool totals
+///
+#[derive(Accounts)]
+pub struct EmergencyUnstake<'info> {
+    /// User withdrawing their VLTR tokens
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Staking pool - only while paused
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump,
+        constraint = staking_pool.is_paused @ StakingError::PoolNotPaused
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// User's staker account
+    #[account(
+        mut,
+        seeds = [STAKER_SEED, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = staker.bump,
+        constraint = staker.owner == user.key() @ StakingError::InvalidAuthority
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// VLTR token mint
+    #[account(
+        constraint = vltr_mint.key() == staking_pool.vltr_mint @ StakingError::InvalidVltrMint
+    )]
+    pub vltr_mint: Account<'info, Mint>,
+
+    /// User's VLTR token account
+    #[account(
+        mut,
+        token::mint = vltr_mint,
+        token::authority = user
+    )]
+    pub user_vltr_account: Account<'info, TokenAccount>,
+
+    /// Pool's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, staking_pool.key().as_ref()],
+        bump = staking_pool.stake_vault_bump,
+        token::mint = vltr_mint,
+        token::authority = staking_pool
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler_emergency_unstake(ctx: Context<EmergencyUnstake>) -> Result<()> {
+    let amount = ctx.accounts.staker.staked_amount;
+    require!(amount > 0, StakingError::InsufficientStake);
+
+    let now = Clock::get()?.unix_timestamp;
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let staker = &mut ctx.accounts.staker;
+
+    // Transfer VLTR from stake vault back to user
+    // Pool PDA signs as authority
+    let vltr_mint_key = staking_pool.vltr_mint;
+    let seeds = &[
+        STAKING_POOL_SEED,
+        vltr_mint_key.as_ref(),
+        &[staking_pool.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                to: ctx.accounts.user_vltr_account.to_account_info(),
+                authority: staking_pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    // Bring streamed rewards up to date so the forfeit includes them
+    staking_pool.accrue_streamed_rewards(now)?;
+    let forfeited = staking_pool.forfeit_all_rewards(staker)?;
+
+    staker.record_unstake(amount, staking_pool.reward_per_token)?;
+
+    // The lock ends with the position it was protecting
+    staker.lock_until = 0;
+    staker.lock_boost_bps = 0;
+
+    staking_pool.reweight_staker(staker, now)?;
+
+    staking_pool.total_staked = staking_pool
+        .total_staked
+        .checked_sub(amount)
+        .ok_or(StakingError::MathUnderflow)?;
+    // Never fail principal recovery on a drifted staker_count
+    staking_pool.staker_count = staking_pool.staker_count.saturating_sub(1);
+
+    msg!(
+        "Emergency unstaked {} VLTR, forfeited {} rewards. Pool total: {}",
+        amount,
+        forfeited,
+        staking_pool.total_staked
+    );
+
+    Ok(())
+}
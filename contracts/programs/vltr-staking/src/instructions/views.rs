@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::{STAKER_SEED, STAKING_POOL_SEED};
+use crate::error::StakingError;
+use crate::state::{Staker, StakingPool};
+
+/// Reward vault solvency, as returned by get_reward_solvency
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RewardSolvency {
+    /// Rewards distributed but not yet claimed (upper bound)
+    pub total_rewards_owed: u64,
+
+    /// Current reward vault balance
+    pub reward_vault_balance: u64,
+
+    /// Whether the vault can cover every outstanding claim
+    pub is_solvent: bool,
+
+    /// How much the vault is short by (0 when solvent)
+    pub shortfall: u64,
+}
+
+/// Read-only view comparing outstanding rewards against the reward vault
+///
+/// Permissionless and non-mutating, so monitors can call it via
+/// simulateTransaction / `.view()` to detect an under-funded reward vault.
+#[derive(Accounts)]
+pub struct GetRewardSolvency<'info> {
+    /// Staking pool
+    #[account(
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Pool's reward vault
+    #[account(
+        constraint = reward_vault.key() == staking_pool.reward_vault @ StakingError::InvalidPDA
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+}
+
+pub fn handler_get_reward_solvency(ctx: Context<GetRewardSolvency>) -> Result<RewardSolvency> {
+    let staking_pool = &ctx.accounts.staking_pool;
+    let reward_vault_balance = ctx.accounts.reward_vault.amount;
+    let shortfall = staking_pool.reward_shortfall(reward_vault_balance);
+
+    Ok(RewardSolvency {
+        total_rewards_owed: staking_pool.total_rewards_owed,
+        reward_vault_balance,
+        is_solvent: shortfall == 0,
+        shortfall,
+    })
+}
+
+/// A staker's claimable rewards, as returned by get_pending_rewards
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PendingRewards {
+    /// Reward tokens `claim` would pay right now
+    pub pending_rewards: u64,
+
+    /// VLTR currently staked
+    pub staked_amount: u64,
+
+    /// Lifetime rewards claimed
+    pub rewards_claimed: u64,
+}
+
+/// Read-only view of what a staker can claim
+///
+/// Runs the same accrual as claim (streamed rewards, then the minimum stake
+/// duration forfeit) on copies of the accounts, so UIs don't have to
+/// replicate the reward_per_token math off-chain.
+#[derive(Accounts)]
+pub struct GetPendingRewards<'info> {
+    /// Staking pool
+    #[account(
+        seeds = [STAKING_POOL_SEED, staking_pool.vltr_mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Staker account to query
+    #[account(
+        seeds = [STAKER_SEED, staking_pool.key().as_ref(), staker.owner.as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+}
+
+pub fn handler_get_pending_rewards(ctx: Context<GetPendingRewards>) -> Result<PendingRewards> {
+    let mut staking_pool = StakingPool::clone(&ctx.accounts.staking_pool);
+    let mut staker = Staker::clone(&ctx.accounts.staker);
+    let now = Clock::get()?.unix_timestamp;
+
+    staking_pool.accrue_streamed_rewards(now)?;
+    staking_pool.forfeit_ineligible_rewards(&mut staker, now)?;
+
+    Ok(PendingRewards {
+        pending_rewards: staker.total_claimable(staking_pool.reward_per_token)?,
+        staked_amount: staker.staked_amount,
+        rewards_claimed: staker.rewards_claimed,
+    })
+}
@@ -41,7 +41,26 @@ pub mod vltr_staking {
         instructions::stake::handler_stake(ctx, amount)
     }
 
-    /// Unstake VLTR tokens (no cooldown)
+    /// Lock the caller's position for a reward multiplier
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    /// * `duration_seconds` - Lock term: 30, 90 or 180 days (1.25x / 1.5x / 2x)
+    ///
+    pub fn lock_stake(ctx: Context<LockStake>, duration_seconds: i64) -> Result<()> {
+        instructions::lock::handler_lock_stake(ctx, duration_seconds)
+    }
+
+    /// Start the unstake cooldown for the caller's position
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn request_unstake(ctx: Context<RequestUnstake>) -> Result<()> {
+        instructions::unstake::handler_request_unstake(ctx)
+    }
+
+    /// Unstake VLTR tokens (after request_unstake if a cooldown is set)
     ///
     /// # Arguments
     /// * `ctx` - Context containing all required accounts
@@ -51,6 +70,18 @@ pub mod vltr_staking {
         instructions::unstake::handler_unstake(ctx, amount)
     }
 
+    /// Withdraw the whole stake while the pool is paused
+    ///
+    /// Ignores locks and the unstake cooldown; all pending rewards are
+    /// forfeited to the remaining stakers.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts (same as unstake)
+    ///
+    pub fn emergency_unstake(ctx: Context<EmergencyUnstake>) -> Result<()> {
+        instructions::unstake::handler_emergency_unstake(ctx)
+    }
+
     /// Claim accumulated USDC rewards
     ///
     /// # Arguments
@@ -60,6 +91,26 @@ pub mod vltr_staking {
         instructions::claim::handler_claim(ctx)
     }
 
+    /// Claim accumulated USDC rewards, creating the user's reward ATA if needed
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn claim_with_init(ctx: Context<ClaimWithInit>) -> Result<()> {
+        instructions::claim::handler_claim_with_init(ctx)
+    }
+
+    /// Claim rewards and add them to the caller's stake
+    ///
+    /// Only for pools whose reward mint is the VLTR mint.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn claim_and_restake(ctx: Context<ClaimAndRestake>) -> Result<()> {
+        instructions::claim::handler_claim_and_restake(ctx)
+    }
+
     /// Distribute USDC rewards to stakers (admin only)
     ///
     /// This should be called after liquidation profits are recorded.
@@ -73,6 +124,65 @@ pub mod vltr_staking {
         instructions::distribute::handler_distribute(ctx, amount)
     }
 
+    /// Stream USDC rewards to stakers over a period (admin only)
+    ///
+    /// Rewards accrue linearly until the stream ends instead of all at once.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts (same as distribute)
+    /// * `amount` - Amount of USDC to stream
+    /// * `duration_seconds` - Stream length in seconds (max 1 year)
+    ///
+    pub fn distribute_streamed(ctx: Context<Distribute>, amount: u64, duration_seconds: i64) -> Result<()> {
+        instructions::distribute::handler_distribute_streamed(ctx, amount, duration_seconds)
+    }
+
+    /// Distribute USDC rewards to several staking pools at once (admin only)
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts; each pool and its
+    ///   reward vault are passed as a pair of remaining accounts
+    /// * `amounts` - Amount of USDC for each pool, in the same order
+    ///
+    pub fn distribute_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeBatch<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        instructions::distribute::handler_distribute_batch(ctx, amounts)
+    }
+
+    /// Distribute a bonus reward token to stakers (admin only)
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    /// * `reward_index` - Bonus reward index (1..MAX_REWARD_TOKENS); index 0
+    ///   is the primary reward, distributed with `distribute`
+    /// * `amount` - Amount of the bonus token to distribute
+    ///
+    pub fn distribute_reward(ctx: Context<DistributeReward>, reward_index: u8, amount: u64) -> Result<()> {
+        instructions::distribute::handler_distribute_reward(ctx, reward_index, amount)
+    }
+
+    /// Claim accumulated rewards of a bonus reward token
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    /// * `reward_index` - Bonus reward index (1..MAX_REWARD_TOKENS); index 0
+    ///   is the primary reward, claimed with `claim`
+    ///
+    pub fn claim_reward(ctx: Context<ClaimReward>, reward_index: u8) -> Result<()> {
+        instructions::claim::handler_claim_reward(ctx, reward_index)
+    }
+
+    /// Register a bonus reward token and create its vault (admin only)
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn add_reward_token(ctx: Context<AddRewardToken>) -> Result<()> {
+        instructions::admin::add_reward_token(ctx)
+    }
+
     /// Pause or unpause the staking pool (admin only)
     ///
     /// # Arguments
@@ -83,13 +193,34 @@ pub mod vltr_staking {
         instructions::admin::pause_pool(ctx, paused)
     }
 
-    /// Transfer admin rights to a new address (admin only)
+    /// Propose a new admin (admin only)
+    ///
+    /// The new admin takes over only by signing accept_admin_transfer
+    /// after the 24h timelock and before the 7-day expiry.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn propose_admin_transfer(ctx: Context<ProposeAdminTransfer>) -> Result<()> {
+        instructions::admin::propose_admin_transfer(ctx)
+    }
+
+    /// Accept a proposed admin transfer (pending admin only)
     ///
     /// # Arguments
     /// * `ctx` - Context containing all required accounts
     ///
-    pub fn transfer_admin(ctx: Context<TransferAdmin>) -> Result<()> {
-        instructions::admin::transfer_admin(ctx)
+    pub fn accept_admin_transfer(ctx: Context<AcceptAdminTransfer>) -> Result<()> {
+        instructions::admin::accept_admin_transfer(ctx)
+    }
+
+    /// Cancel a proposed admin transfer (admin only)
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+        instructions::admin::cancel_admin_transfer(ctx)
     }
 
     /// Update the reward vault address (admin only)
@@ -100,4 +231,123 @@ pub mod vltr_staking {
     pub fn update_reward_vault(ctx: Context<UpdateRewardVault>) -> Result<()> {
         instructions::admin::update_reward_vault(ctx)
     }
+
+    /// Move reward vault ownership to the staking pool PDA (admin only)
+    ///
+    /// After this, claims are signed by the staking pool PDA and no longer
+    /// require the previous vault owner to co-sign.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn set_reward_vault_authority(ctx: Context<SetRewardVaultAuthority>) -> Result<()> {
+        instructions::admin::set_reward_vault_authority(ctx)
+    }
+
+    /// Configure a time-decaying reward boost for early stakers (admin only)
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    /// * `multiplier_bps` - Starting multiplier (10_000 = 1x, max 30_000)
+    /// * `end_time` - Unix timestamp when the boost has decayed to 1x
+    ///
+    pub fn set_launch_boost(ctx: Context<SetLaunchBoost>, multiplier_bps: u16, end_time: i64) -> Result<()> {
+        instructions::admin::set_launch_boost(ctx, multiplier_bps, end_time)
+    }
+
+    /// Set the wallet allowed to distribute rewards besides the admin (admin only)
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    /// * `distributor` - New distributor (Pubkey::default() removes it)
+    ///
+    pub fn set_distributor(ctx: Context<SetDistributor>, distributor: Pubkey) -> Result<()> {
+        instructions::admin::set_distributor(ctx, distributor)
+    }
+
+    /// Set the delay between request_unstake and unstake (admin only)
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    /// * `cooldown_seconds` - Cooldown in seconds (0 disables, max 30 days)
+    ///
+    pub fn set_unstake_cooldown(ctx: Context<SetUnstakeCooldown>, cooldown_seconds: i64) -> Result<()> {
+        instructions::admin::set_unstake_cooldown(ctx, cooldown_seconds)
+    }
+
+    /// Set how long a position must be held before its rewards are kept (admin only)
+    ///
+    /// Rewards accrued within this window of a stake change are forfeited to
+    /// the other stakers if the position stakes, unstakes or claims early.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    /// * `duration_seconds` - Minimum duration in seconds (0 disables, max 30 days)
+    ///
+    pub fn set_min_stake_duration(ctx: Context<SetMinStakeDuration>, duration_seconds: i64) -> Result<()> {
+        instructions::admin::set_min_stake_duration(ctx, duration_seconds)
+    }
+
+    /// Refresh a staker's effective stake to the current boost (permissionless)
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn poke(ctx: Context<Poke>) -> Result<()> {
+        instructions::poke::handler_poke(ctx)
+    }
+
+    /// Recompute staker_count from every Staker PDA (admin only)
+    ///
+    /// Migration for pools whose count drifted before stake counted
+    /// returning stakers; new pools do not need it.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts; all of the pool's
+    ///   Staker PDAs are passed as remaining accounts, each exactly once
+    ///
+    pub fn recount_stakers<'info>(ctx: Context<'_, '_, 'info, 'info, RecountStakers<'info>>) -> Result<()> {
+        instructions::admin::recount_stakers(ctx)
+    }
+
+    /// Grow a staking pool created with the original layout (admin only)
+    ///
+    /// Sets total_effective_stake from total_staked. Must run before the
+    /// pool's stakers are migrated.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn migrate_staking_pool(ctx: Context<MigrateStakingPool>) -> Result<()> {
+        instructions::migrate::handler_migrate_staking_pool(ctx)
+    }
+
+    /// Grow a staker position created with the original layout
+    ///
+    /// Sets effective_stake from staked_amount. Permissionless.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn migrate_staker(ctx: Context<MigrateStaker>) -> Result<()> {
+        instructions::migrate::handler_migrate_staker(ctx)
+    }
+
+    /// Report whether the reward vault covers all outstanding rewards (read-only)
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn get_reward_solvency(ctx: Context<GetRewardSolvency>) -> Result<RewardSolvency> {
+        instructions::views::handler_get_reward_solvency(ctx)
+    }
+
+    /// Report the rewards a staker can claim right now (read-only)
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing all required accounts
+    ///
+    pub fn get_pending_rewards(ctx: Context<GetPendingRewards>) -> Result<PendingRewards> {
+        instructions::views::handler_get_pending_rewards(ctx)
+    }
 }
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::REWARD_PRECISION;
+use crate::constants::{BOOST_BPS_DENOMINATOR, LOCK_TIERS, MAX_BONUS_REWARDS, REWARD_PRECISION};
 use crate::error::StakingError;
 
 /// Per-user staking position
@@ -19,7 +19,7 @@ pub struct Staker {
 
     /// Reward debt - used for pro-rata calculation
     /// This tracks how much reward_per_token the user has already "claimed"
-    /// When claiming: pending = staked * (pool.reward_per_token - reward_debt) / PRECISION
+    /// When claiming: pending = effective_stake * (pool.reward_per_token - reward_debt) / PRECISION
     pub reward_debt: u128,
 
     /// Total rewards claimed (lifetime)
@@ -33,6 +33,34 @@ pub struct Staker {
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// Boost-weighted stake used for reward accrual
+    /// staked_amount * boost multiplier at the last stake change or poke
+    pub effective_stake: u64,
+
+    /// Rewards accrued but not yet claimed, banked on every stake change
+    /// Lets users stake/unstake/claim in any order without losing rewards
+    pub pending_unclaimed: u64,
+
+    /// When a requested unstake becomes available (0 = not requested)
+    /// Cleared by any stake or unstake, so each exit needs a fresh request
+    pub unstake_available_at: i64,
+
+    /// Reward debt per bonus reward slot (parallel to pool.bonus_rewards)
+    pub bonus_reward_debt: [u128; MAX_BONUS_REWARDS],
+
+    /// Banked but unclaimed rewards per bonus reward slot
+    pub bonus_pending_unclaimed: [u64; MAX_BONUS_REWARDS],
+
+    /// Bonus rewards claimed per slot (lifetime)
+    pub bonus_rewards_claimed: [u64; MAX_BONUS_REWARDS],
+
+    /// Position cannot be unstaked before this time (0 = not locked)
+    pub lock_until: i64,
+
+    /// Reward multiplier for the current lock in basis points
+    /// Applies until lock_until; 0 when never locked
+    pub lock_boost_bps: u16,
 }
 
 impl Staker {
@@ -46,20 +74,83 @@ impl Staker {
         8 +  // first_stake_time
         8 +  // last_stake_time
         1 +  // bump
-        32;  // padding for future fields
+        8 +  // effective_stake
+        8 +  // pending_unclaimed
+        8 +  // unstake_available_at
+        16 * MAX_BONUS_REWARDS + // bonus_reward_debt
+        8 * MAX_BONUS_REWARDS +  // bonus_pending_unclaimed
+        8 * MAX_BONUS_REWARDS +  // bonus_rewards_claimed
+        8 +  // lock_until
+        2 +  // lock_boost_bps
+        8;   // padding for future fields
+
+    /// Size of positions created with the original layout, which ended with
+    /// 32 bytes of padding after bump. They must be grown with migrate_staker
+    /// before the current layout can be read.
+    pub const LEGACY_SIZE: usize = 8 + 32 + 32 + 8 + 16 + 8 + 8 + 8 + 1 + 32;
+
+    /// Fill in the fields a position migrated from the original layout is
+    /// missing
+    ///
+    /// The position earns at 1x, which is what its pool's
+    /// total_effective_stake already counts it at after migration.
+    pub fn migrate_from_legacy(&mut self) {
+        self.effective_stake = self.staked_amount;
+    }
 
     /// Calculate pending rewards for this staker
-    /// Formula: pending = staked_amount * (pool_reward_per_token - reward_debt) / PRECISION
+    /// Formula: pending = effective_stake * (pool_reward_per_token - reward_debt) / PRECISION
     pub fn calculate_pending_rewards(&self, pool_reward_per_token: u128) -> Result<u64> {
-        if self.staked_amount == 0 {
+        self.accrued_since(self.reward_debt, pool_reward_per_token)
+    }
+
+    /// Fresh accrual on a bonus reward slot since its reward debt
+    pub fn calculate_bonus_pending(&self, slot: usize, bonus_reward_per_token: u128) -> Result<u64> {
+        self.accrued_since(self.bonus_reward_debt[slot], bonus_reward_per_token)
+    }
+
+    /// Bonus rewards the staker can claim right now on a slot
+    pub fn bonus_claimable(&self, slot: usize, bonus_reward_per_token: u128) -> Result<u64> {
+        let fresh = self.calculate_bonus_pending(slot, bonus_reward_per_token)?;
+
+        Ok(self.bonus_pending_unclaimed[slot]
+            .checked_add(fresh)
+            .ok_or(StakingError::MathOverflow)?)
+    }
+
+    /// Bank fresh accrual on every bonus slot
+    /// Must be called BEFORE effective_stake changes
+    pub fn bank_bonus_rewards(&mut self, bonus_rewards_per_token: [u128; MAX_BONUS_REWARDS]) -> Result<()> {
+        for (slot, reward_per_token) in bonus_rewards_per_token.into_iter().enumerate() {
+            self.bonus_pending_unclaimed[slot] = self.bonus_claimable(slot, reward_per_token)?;
+            self.bonus_reward_debt[slot] = reward_per_token;
+        }
+
+        Ok(())
+    }
+
+    /// Record a bonus reward claim
+    pub fn record_bonus_claim(&mut self, slot: usize, amount: u64, bonus_reward_per_token: u128) -> Result<()> {
+        self.bonus_rewards_claimed[slot] = self.bonus_rewards_claimed[slot]
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        self.bonus_pending_unclaimed[slot] = 0;
+        self.bonus_reward_debt[slot] = bonus_reward_per_token;
+
+        Ok(())
+    }
+
+    /// effective_stake * (reward_per_token - reward_debt) / PRECISION
+    fn accrued_since(&self, reward_debt: u128, reward_per_token: u128) -> Result<u64> {
+        if self.effective_stake == 0 {
             return Ok(0);
         }
 
-        let reward_diff = pool_reward_per_token
-            .checked_sub(self.reward_debt)
+        let reward_diff = reward_per_token
+            .checked_sub(reward_debt)
             .ok_or(StakingError::MathUnderflow)?;
 
-        let pending = (self.staked_amount as u128)
+        let pending = (self.effective_stake as u128)
             .checked_mul(reward_diff)
             .ok_or(StakingError::MathOverflow)?
             .checked_div(REWARD_PRECISION)
@@ -69,16 +160,46 @@ impl Staker {
         Ok(pending.min(u64::MAX as u128) as u64)
     }
 
+    /// Total rewards the staker can claim right now
+    /// Formula: pending_unclaimed + fresh accrual since reward_debt
+    pub fn total_claimable(&self, pool_reward_per_token: u128) -> Result<u64> {
+        let fresh = self.calculate_pending_rewards(pool_reward_per_token)?;
+
+        Ok(self
+            .pending_unclaimed
+            .checked_add(fresh)
+            .ok_or(StakingError::MathOverflow)?)
+    }
+
     /// Update reward_debt to current pool reward_per_token
     /// Called after claiming or when stake amount changes
     pub fn update_reward_debt(&mut self, pool_reward_per_token: u128) {
         self.reward_debt = pool_reward_per_token;
     }
 
+    /// Move fresh accrual into pending_unclaimed and reset reward_debt
+    /// Must be called BEFORE effective_stake changes so accrual is computed on
+    /// the old weight
+    pub fn bank_pending_rewards(&mut self, pool_reward_per_token: u128) -> Result<()> {
+        let fresh = self.calculate_pending_rewards(pool_reward_per_token)?;
+
+        self.pending_unclaimed = self
+            .pending_unclaimed
+            .checked_add(fresh)
+            .ok_or(StakingError::MathOverflow)?;
+
+        self.update_reward_debt(pool_reward_per_token);
+
+        Ok(())
+    }
+
     /// Record a stake action
     pub fn record_stake(&mut self, amount: u64, pool_reward_per_token: u128) -> Result<()> {
         let clock = Clock::get()?;
 
+        // Bank rewards earned on the old balance before it changes
+        self.bank_pending_rewards(pool_reward_per_token)?;
+
         if self.staked_amount == 0 {
             self.first_stake_time = clock.unix_timestamp;
         }
@@ -90,8 +211,103 @@ impl Staker {
 
         self.last_stake_time = clock.unix_timestamp;
 
-        // Important: Update reward_debt so new stake doesn't get retroactive rewards
-        self.update_reward_debt(pool_reward_per_token);
+        // New stake restarts the cooldown, so a request cannot be made early
+        // and kept open for stake added right before a distribution
+        self.unstake_available_at = 0;
+
+        Ok(())
+    }
+
+    /// Record rewards compounded into the position by claim_and_restake
+    ///
+    /// Unlike `record_stake`, this is not a stake change: last_stake_time and
+    /// a pending unstake request are left alone, since the amount was earned
+    /// by the position itself rather than added from outside.
+    pub fn record_restake(&mut self, amount: u64, pool_reward_per_token: u128) -> Result<()> {
+        // Bank rewards earned on the old balance before it changes
+        self.bank_pending_rewards(pool_reward_per_token)?;
+
+        self.staked_amount = self
+            .staked_amount
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Whether rewards accrued since the last stake change may be kept
+    ///
+    /// Always true when the pool has no minimum stake duration configured.
+    pub fn is_reward_eligible(&self, now: i64, min_stake_duration: i64) -> bool {
+        min_stake_duration == 0 || now.saturating_sub(self.last_stake_time) >= min_stake_duration
+    }
+
+    /// Lock multiplier in basis points at `now` (1x once the lock expires)
+    pub fn lock_boost_at(&self, now: i64) -> u64 {
+        if now < self.lock_until && self.lock_boost_bps as u64 > BOOST_BPS_DENOMINATOR {
+            self.lock_boost_bps as u64
+        } else {
+            BOOST_BPS_DENOMINATOR
+        }
+    }
+
+    /// Lock the position for one of the LOCK_TIERS durations
+    ///
+    /// A lock can be extended or moved to a higher tier but never shortened.
+    /// The caller must re-weight the staker afterwards.
+    pub fn apply_lock(&mut self, now: i64, duration_seconds: i64) -> Result<()> {
+        require!(self.staked_amount > 0, StakingError::InsufficientStake);
+
+        let (_, boost_bps) = LOCK_TIERS
+            .iter()
+            .copied()
+            .find(|(duration, _)| *duration == duration_seconds)
+            .ok_or(StakingError::InvalidLockDuration)?;
+
+        let lock_until = now
+            .checked_add(duration_seconds)
+            .ok_or(StakingError::MathOverflow)?;
+        require!(
+            lock_until >= self.lock_until && boost_bps as u64 >= self.lock_boost_at(now),
+            StakingError::LockCannotBeShortened
+        );
+
+        self.lock_until = lock_until;
+        self.lock_boost_bps = boost_bps;
+
+        Ok(())
+    }
+
+    /// Check that the position is not locked
+    pub fn check_unlocked(&self, now: i64) -> Result<()> {
+        require!(now >= self.lock_until, StakingError::StakeLocked);
+        Ok(())
+    }
+
+    /// Start the unstake cooldown
+    pub fn request_unstake(&mut self, now: i64, cooldown_seconds: i64) -> Result<()> {
+        require!(self.staked_amount > 0, StakingError::InsufficientStake);
+
+        self.unstake_available_at = now
+            .checked_add(cooldown_seconds)
+            .ok_or(StakingError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Check that the staker may unstake now
+    ///
+    /// Always passes when the pool has no cooldown configured.
+    pub fn check_unstake_cooldown(&self, now: i64, cooldown_seconds: i64) -> Result<()> {
+        if cooldown_seconds == 0 {
+            return Ok(());
+        }
+
+        require!(self.unstake_available_at != 0, StakingError::UnstakeNotRequested);
+        require!(
+            now >= self.unstake_available_at,
+            StakingError::UnstakeCooldownNotElapsed
+        );
 
         Ok(())
     }
@@ -103,17 +319,33 @@ impl Staker {
             StakingError::InsufficientStake
         );
 
+        // Bank rewards earned on the old balance before it changes
+        self.bank_pending_rewards(pool_reward_per_token)?;
+
         self.staked_amount = self
             .staked_amount
             .checked_sub(amount)
             .ok_or(StakingError::MathUnderflow)?;
 
         self.last_stake_time = Clock::get()?.unix_timestamp;
+        self.unstake_available_at = 0;
+
+        Ok(())
+    }
 
-        // Update reward_debt
+    /// Forfeit all pending rewards (e.g. when the position is slashed)
+    ///
+    /// Clears banked rewards, resets reward_debt to the current pool
+    /// reward_per_token and returns the forfeited amount. The caller is
+    /// responsible for passing it to
+    /// `StakingPool::redistribute_forfeited_rewards`.
+    pub fn forfeit_pending_rewards(&mut self, pool_reward_per_token: u128) -> Result<u64> {
+        let forfeited = self.total_claimable(pool_reward_per_token)?;
+
+        self.pending_unclaimed = 0;
         self.update_reward_debt(pool_reward_per_token);
 
-        Ok(())
+        Ok(forfeited)
     }
 
     /// Record a claim action
@@ -123,9 +355,160 @@ impl Staker {
             .checked_add(amount)
             .ok_or(StakingError::MathOverflow)?;
 
+        // Banked rewards are paid out with the claim
+        self.pending_unclaimed = 0;
+
         // Update reward_debt to prevent double-claiming
         self.update_reward_debt(pool_reward_per_token);
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn banking_preserves_rewards_across_stake_changes() {
+        let mut staker = Staker {
+            staked_amount: 100,
+            effective_stake: 100,
+            ..Staker::default()
+        };
+
+        // 5 tokens per staked token accrue, then the stake doubles
+        let rpt_1 = 5 * REWARD_PRECISION;
+        staker.bank_pending_rewards(rpt_1).unwrap();
+        staker.staked_amount = 200;
+        staker.effective_stake = 200;
+        assert_eq!(staker.pending_unclaimed, 500);
+        assert_eq!(staker.calculate_pending_rewards(rpt_1).unwrap(), 0);
+
+        // 1 more per token accrues on the new balance
+        let rpt_2 = 6 * REWARD_PRECISION;
+        assert_eq!(staker.total_claimable(rpt_2).unwrap(), 700);
+
+        // Fully unstake: banked rewards survive a zero balance
+        staker.bank_pending_rewards(rpt_2).unwrap();
+        staker.staked_amount = 0;
+        staker.effective_stake = 0;
+        assert_eq!(staker.total_claimable(7 * REWARD_PRECISION).unwrap(), 700);
+
+        staker.record_claim(700, 7 * REWARD_PRECISION).unwrap();
+        assert_eq!(staker.pending_unclaimed, 0);
+        assert_eq!(staker.total_claimable(7 * REWARD_PRECISION).unwrap(), 0);
+    }
+
+    #[test]
+    fn forfeit_clears_banked_and_fresh_rewards() {
+        let mut staker = Staker {
+            staked_amount: 100,
+            effective_stake: 100,
+            pending_unclaimed: 40,
+            reward_debt: REWARD_PRECISION,
+            ..Staker::default()
+        };
+
+        let rpt = 3 * REWARD_PRECISION;
+        assert_eq!(staker.forfeit_pending_rewards(rpt).unwrap(), 240);
+        assert_eq!(staker.pending_unclaimed, 0);
+        assert_eq!(staker.reward_debt, rpt);
+        assert_eq!(staker.total_claimable(rpt).unwrap(), 0);
+
+        // Accrual resumes from the forfeit point
+        assert_eq!(staker.total_claimable(4 * REWARD_PRECISION).unwrap(), 100);
+    }
+
+    #[test]
+    fn unstake_cooldown_requires_request_and_wait() {
+        let mut staker = Staker {
+            staked_amount: 100,
+            ..Staker::default()
+        };
+
+        // No cooldown configured: unstake is always allowed
+        assert!(staker.check_unstake_cooldown(1_000, 0).is_ok());
+
+        assert!(staker.check_unstake_cooldown(1_000, 60).is_err());
+        staker.request_unstake(1_000, 60).unwrap();
+        assert_eq!(staker.unstake_available_at, 1_060);
+        assert!(staker.check_unstake_cooldown(1_059, 60).is_err());
+        assert!(staker.check_unstake_cooldown(1_060, 60).is_ok());
+
+        // Nothing staked, nothing to request
+        assert!(Staker::default().request_unstake(1_000, 60).is_err());
+    }
+
+    #[test]
+    fn lock_tiers_boost_until_expiry_and_cannot_shorten() {
+        let day = 24 * 60 * 60;
+        let mut staker = Staker {
+            staked_amount: 100,
+            ..Staker::default()
+        };
+        assert_eq!(staker.lock_boost_at(0), BOOST_BPS_DENOMINATOR);
+        assert!(staker.apply_lock(0, 45 * day).is_err());
+
+        staker.apply_lock(0, 90 * day).unwrap();
+        assert_eq!(staker.lock_boost_at(0), 15_000);
+        assert!(staker.check_unlocked(89 * day).is_err());
+
+        // A shorter tier cannot replace a longer one
+        assert!(staker.apply_lock(day, 30 * day).is_err());
+        staker.apply_lock(day, 180 * day).unwrap();
+        assert_eq!(staker.lock_boost_at(day), 20_000);
+
+        // Expired lock: back to 1x and free to unstake
+        assert_eq!(staker.lock_boost_at(181 * day), BOOST_BPS_DENOMINATOR);
+        assert!(staker.check_unlocked(181 * day).is_ok());
+    }
+
+    #[test]
+    fn restake_keeps_stake_timers() {
+        let mut staker = Staker {
+            staked_amount: 100,
+            effective_stake: 100,
+            last_stake_time: 1_000,
+            unstake_available_at: 5_000,
+            ..Staker::default()
+        };
+
+        staker.record_restake(50, 2 * REWARD_PRECISION).unwrap();
+        assert_eq!(staker.staked_amount, 150);
+        assert_eq!(staker.pending_unclaimed, 200);
+        assert_eq!(staker.reward_debt, 2 * REWARD_PRECISION);
+        assert_eq!(staker.last_stake_time, 1_000);
+        assert_eq!(staker.unstake_available_at, 5_000);
+    }
+
+    #[test]
+    fn legacy_position_migrates_with_its_rewards() {
+        let original = Staker {
+            pool: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            staked_amount: 100,
+            reward_debt: 2 * REWARD_PRECISION,
+            bump: 255,
+            ..Staker::default()
+        };
+
+        // The original layout: every field up to bump, then padding
+        let mut data = Vec::new();
+        original.try_serialize(&mut data).unwrap();
+        data.truncate(Staker::LEGACY_SIZE - 32);
+        data.resize(Staker::LEGACY_SIZE, 0);
+        assert!(Staker::try_deserialize(&mut &data[..]).is_err());
+
+        // migrate_staker zero-extends the account before reading it
+        data.resize(Staker::SIZE, 0);
+        let mut staker = Staker::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(staker.total_claimable(5 * REWARD_PRECISION).unwrap(), 0);
+
+        staker.migrate_from_legacy();
+        assert_eq!(staker.owner, original.owner);
+        assert_eq!(staker.bump, 255);
+        assert_eq!(staker.effective_stake, 100);
+        assert_eq!(staker.total_claimable(5 * REWARD_PRECISION).unwrap(), 300);
+    }
+}
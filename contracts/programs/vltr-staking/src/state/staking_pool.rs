@@ -1,7 +1,48 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{MAX_REWARD_PER_DISTRIBUTION, REWARD_PRECISION};
+use crate::constants::{
+    BOOST_BPS_DENOMINATOR, MAX_BONUS_REWARDS, MAX_REWARD_DURATION_SECONDS,
+    MAX_REWARD_PER_DISTRIBUTION, MAX_REWARD_PER_TOKEN_INCREASE, MIN_DISTRIBUTE_FRACTION_DECIMALS,
+    REWARD_PRECISION,
+};
 use crate::error::StakingError;
+use crate::state::Staker;
+
+/// A bonus reward token distributed alongside the primary reward
+///
+/// Unused slots have a default mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BonusReward {
+    /// Bonus reward token mint
+    pub mint: Pubkey,
+
+    /// Vault holding this reward, owned by the staking pool PDA
+    /// PDA: ["reward_vault", staking_pool, mint]
+    pub vault: Pubkey,
+
+    /// Accumulated rewards per effective stake (scaled by REWARD_PRECISION)
+    pub reward_per_token: u128,
+
+    /// Total rewards distributed (lifetime)
+    pub total_distributed: u64,
+
+    /// Rewards distributed but not yet claimed (upper bound)
+    pub total_owed: u64,
+}
+
+impl BonusReward {
+    /// Serialized size
+    pub const SIZE: usize = 32 + // mint
+        32 + // vault
+        16 + // reward_per_token (u128)
+        8 +  // total_distributed
+        8;   // total_owed
+
+    /// Whether this slot has been configured
+    pub fn is_configured(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+}
 
 /// Global staking pool state
 /// PDA: ["staking_pool", vltr_mint]
@@ -49,6 +90,64 @@ pub struct StakingPool {
 
     /// Stake vault bump seed
     pub stake_vault_bump: u8,
+
+    /// Sum of all stakers' effective (boost-weighted) stake
+    /// This, not total_staked, is the denominator of reward_per_token
+    pub total_effective_stake: u64,
+
+    /// Total rewards forfeited by stakers (lifetime)
+    /// Forfeited rewards are rolled back into reward_per_token for the
+    /// remaining stakers instead of being stranded in the reward vault
+    pub total_forfeited: u64,
+
+    /// Running total of rewards distributed but not yet claimed
+    /// Increased on distribute, decreased on claim and when forfeited rewards
+    /// are left unallocated. Per-staker payouts round down, so this is an
+    /// upper bound on what stakers can actually claim.
+    pub total_rewards_owed: u64,
+
+    /// Minimum amount per distribute call, in reward mint base units
+    /// Derived from the reward mint's decimals at initialization
+    pub min_distribute: u64,
+
+    /// Launch boost multiplier in basis points (10_000 = 1x, 0 = no boost)
+    pub boost_multiplier_bps: u16,
+
+    /// When the launch boost was configured (decay starts here)
+    pub boost_start_time: i64,
+
+    /// When the launch boost has fully decayed to 1x
+    pub boost_end_time: i64,
+
+    /// Seconds between request_unstake and unstake (0 = no cooldown)
+    pub unstake_cooldown_seconds: i64,
+
+    /// Seconds a position must go without a stake change before its accrued
+    /// rewards can be banked or claimed (0 = always eligible)
+    pub min_stake_duration_for_rewards: i64,
+
+    /// Bonus reward tokens (reward indices 1..MAX_REWARD_TOKENS)
+    pub bonus_rewards: [BonusReward; MAX_BONUS_REWARDS],
+
+    /// Primary rewards streamed per second (scaled by REWARD_PRECISION)
+    pub reward_rate: u128,
+
+    /// When the current reward stream ends
+    pub reward_period_finish: i64,
+
+    /// Last time streamed rewards were accrued into reward_per_token
+    pub reward_last_update_time: i64,
+
+    /// Proposed new admin (Pubkey::default() if none)
+    /// Becomes admin only when it signs accept_admin_transfer
+    pub pending_admin: Pubkey,
+
+    /// When the pending admin transfer was proposed
+    pub admin_change_timestamp: i64,
+
+    /// Hot wallet allowed to distribute rewards alongside the admin
+    /// (Pubkey::default() if none). Has no other privileges
+    pub distributor: Pubkey,
 }
 
 impl StakingPool {
@@ -67,29 +166,66 @@ impl StakingPool {
         1 +  // is_paused
         1 +  // bump
         1 +  // stake_vault_bump
-        64;  // padding for future fields
+        8 +  // total_effective_stake
+        8 +  // total_forfeited
+        8 +  // total_rewards_owed
+        8 +  // min_distribute
+        2 +  // boost_multiplier_bps
+        8 +  // boost_start_time
+        8 +  // boost_end_time
+        8 +  // unstake_cooldown_seconds
+        8 +  // min_stake_duration_for_rewards
+        BonusReward::SIZE * MAX_BONUS_REWARDS + // bonus_rewards
+        16 + // reward_rate (u128)
+        8 +  // reward_period_finish
+        8 +  // reward_last_update_time
+        32 + // pending_admin
+        8 +  // admin_change_timestamp
+        32 + // distributor
+        6;   // padding for future fields
 
-    /// Update reward_per_token when new rewards are distributed
-    /// Formula: reward_per_token += (new_rewards * PRECISION) / total_staked
+    /// Size of pools created with the original layout, which ended with 64
+    /// bytes of padding after stake_vault_bump. They must be grown with
+    /// migrate_staking_pool before the current layout can be read.
+    pub const LEGACY_SIZE: usize = 8 + (32 * 5) + 8 + 8 + 16 + 8 + 8 + 1 + 1 + 1 + 64;
+
+    /// Fill in the fields a pool migrated from the original layout is missing
     ///
-    /// SECURITY FIX-16: Added MAX_REWARD_PER_DISTRIBUTION cap
-    pub fn update_reward_per_token(&mut self, new_rewards: u64) -> Result<()> {
-        if self.total_staked == 0 {
-            // No stakers, rewards cannot be distributed
-            // This shouldn't happen if called correctly
-            return Ok(());
-        }
+    /// Every original position earned at 1x, so total_effective_stake starts
+    /// at total_staked. Everything else starts unset, as on a new pool;
+    /// total_rewards_owed cannot be reconstructed and starts at 0.
+    pub fn migrate_from_legacy(&mut self, reward_decimals: u8) -> Result<()> {
+        self.total_effective_stake = self.total_staked;
+        self.min_distribute = Self::min_distribute_for_decimals(reward_decimals)?;
+        Ok(())
+    }
 
-        // SECURITY FIX-16: Enforce reward distribution cap to prevent overflow
-        require!(
-            new_rewards <= MAX_REWARD_PER_DISTRIBUTION,
-            StakingError::RewardExceedsMax
-        );
+    /// Whether `authority` may distribute rewards: the admin or the distributor
+    pub fn can_distribute(&self, authority: &Pubkey) -> bool {
+        *authority == self.admin
+            || (self.distributor != Pubkey::default() && *authority == self.distributor)
+    }
+
+    /// Minimum distribution for a reward mint with `decimals` decimals
+    ///
+    /// 0.001 whole tokens, or 1 base unit for mints with fewer than 3 decimals.
+    pub fn min_distribute_for_decimals(decimals: u8) -> Result<u64> {
+        10u64
+            .checked_pow(decimals.saturating_sub(MIN_DISTRIBUTE_FRACTION_DECIMALS) as u32)
+            .ok_or(StakingError::MathOverflow.into())
+    }
 
+    /// reward_per_token increase for distributing `new_rewards` right now
+    /// Formula: (new_rewards * PRECISION) / total_effective_stake
+    ///
+    /// SECURITY FIX-16: Bounds the result by MAX_REWARD_PER_TOKEN_INCREASE so
+    /// a dust total stake cannot absorb a distribution. MAX_REWARD_PER_DISTRIBUTION
+    /// is enforced by the distribute handlers before any tokens move.
+    pub fn reward_per_token_increase(&self, new_rewards: u64) -> Result<u128> {
         let reward_increase = (new_rewards as u128)
             .checked_mul(REWARD_PRECISION)
             .ok_or(StakingError::MathOverflow)?
-            .checked_div(self.total_staked as u128)
+            .checked_div(self.total_effective_stake as u128)
             .ok_or(StakingError::DivisionByZero)?;
 
         // Ensure reward_increase is non-zero to prevent reward loss from rounding
@@ -98,6 +234,26 @@ impl StakingPool {
             reward_increase > 0,
             StakingError::InvalidAmount
         );
+        require!(
+            reward_increase <= MAX_REWARD_PER_TOKEN_INCREASE,
+            StakingError::RewardPerTokenIncreaseExceedsMax
+        );
+
+        Ok(reward_increase)
+    }
+
+    /// Update reward_per_token when new rewards are distributed
+    /// Formula: reward_per_token += (new_rewards * PRECISION) / total_effective_stake
+    ///
+    /// SECURITY FIX-16: Bounded by reward_per_token_increase. Fails with
+    /// NoStakersToDistribute when there is no effective stake to earn it.
+    pub fn update_reward_per_token(&mut self, new_rewards: u64) -> Result<()> {
+        require!(
+            self.total_effective_stake > 0,
+            StakingError::NoStakersToDistribute
+        );
+
+        let reward_increase = self.reward_per_token_increase(new_rewards)?;
 
         self.reward_per_token = self
             .reward_per_token
@@ -109,8 +265,691 @@ impl StakingPool {
             .checked_add(new_rewards)
             .ok_or(StakingError::MathOverflow)?;
 
+        self.total_rewards_owed = self
+            .total_rewards_owed
+            .checked_add(new_rewards)
+            .ok_or(StakingError::MathOverflow)?;
+
         self.last_distribution_time = Clock::get()?.unix_timestamp;
 
         Ok(())
     }
+
+    /// Accrue streamed rewards into reward_per_token up to `now`
+    ///
+    /// Must run before any effective stake changes or rewards are paid, so
+    /// the elapsed stream is split across the stake that was present for it.
+    /// Time with no effective stake is skipped; its share stays unallocated
+    /// in the reward vault.
+    pub fn accrue_streamed_rewards(&mut self, now: i64) -> Result<()> {
+        let until = now.min(self.reward_period_finish);
+        if self.reward_rate == 0 || until <= self.reward_last_update_time {
+            self.reward_last_update_time = self.reward_last_update_time.max(until);
+            return Ok(());
+        }
+
+        let elapsed = (until - self.reward_last_update_time) as u128;
+        self.reward_last_update_time = until;
+
+        if self.total_effective_stake == 0 {
+            return Ok(());
+        }
+
+        let streamed = self
+            .reward_rate
+            .checked_mul(elapsed)
+            .ok_or(StakingError::MathOverflow)?;
+        let emitted = u64::try_from(streamed / REWARD_PRECISION).map_err(|_| StakingError::MathOverflow)?;
+
+        self.reward_per_token = self
+            .reward_per_token
+            .checked_add(streamed / self.total_effective_stake as u128)
+            .ok_or(StakingError::MathOverflow)?;
+        self.total_rewards_distributed = self
+            .total_rewards_distributed
+            .checked_add(emitted)
+            .ok_or(StakingError::MathOverflow)?;
+        self.total_rewards_owed = self
+            .total_rewards_owed
+            .checked_add(emitted)
+            .ok_or(StakingError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Start (or top up) a reward stream of `amount` over `duration` seconds
+    ///
+    /// Anything left of a running stream is rolled into the new one.
+    /// Streamed rewards must be accrued up to `now` first.
+    pub fn start_reward_stream(&mut self, amount: u64, duration: i64, now: i64) -> Result<()> {
+        require!(
+            duration > 0 && duration <= MAX_REWARD_DURATION_SECONDS,
+            StakingError::InvalidRewardDuration
+        );
+
+        let leftover = if now < self.reward_period_finish {
+            self.reward_rate
+                .checked_mul((self.reward_period_finish - now) as u128)
+                .ok_or(StakingError::MathOverflow)?
+        } else {
+            0
+        };
+        let total = (amount as u128)
+            .checked_mul(REWARD_PRECISION)
+            .ok_or(StakingError::MathOverflow)?
+            .checked_add(leftover)
+            .ok_or(StakingError::MathOverflow)?;
+        require!(
+            total / REWARD_PRECISION <= MAX_REWARD_PER_DISTRIBUTION as u128,
+            StakingError::RewardExceedsMax
+        );
+
+        self.reward_rate = total / duration as u128;
+        self.reward_last_update_time = now;
+        self.reward_period_finish = now
+            .checked_add(duration)
+            .ok_or(StakingError::MathOverflow)?;
+        self.last_distribution_time = now;
+
+        Ok(())
+    }
+
+    /// Roll forfeited rewards back into reward_per_token for remaining stakers
+    ///
+    /// `excluded_stake` is the effective stake still held by the forfeiting
+    /// staker (if any).
+    /// It is left out of the denominator so the forfeiting staker does not earn
+    /// back a share of their own forfeited rewards. If they keep a position,
+    /// the caller must move their reward_debt to the new reward_per_token.
+    ///
+    /// If nobody else is staked, the rewards stay unallocated in the reward vault.
+    /// The amount is not added to total_rewards_distributed because it was
+    /// already counted when it was first distributed.
+    pub fn redistribute_forfeited_rewards(&mut self, amount: u64, excluded_stake: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        self.total_forfeited = self
+            .total_forfeited
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+
+        let remaining_stake = self
+            .total_effective_stake
+            .checked_sub(excluded_stake)
+            .ok_or(StakingError::MathUnderflow)?;
+
+        if remaining_stake == 0 {
+            // Nobody can claim these rewards any more
+            self.total_rewards_owed = self.total_rewards_owed.saturating_sub(amount);
+            msg!("No remaining stakers - {} forfeited rewards left unallocated", amount);
+            return Ok(());
+        }
+
+        let reward_increase = (amount as u128)
+            .checked_mul(REWARD_PRECISION)
+            .ok_or(StakingError::MathOverflow)?
+            .checked_div(remaining_stake as u128)
+            .ok_or(StakingError::DivisionByZero)?;
+
+        self.reward_per_token = self
+            .reward_per_token
+            .checked_add(reward_increase)
+            .ok_or(StakingError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Bonus slot for a reward index (1..MAX_REWARD_TOKENS)
+    ///
+    /// Index 0 is the primary reward, which has its own instructions.
+    pub fn bonus_slot(&self, reward_index: u8) -> Result<usize> {
+        let slot = (reward_index as usize)
+            .checked_sub(1)
+            .ok_or(StakingError::InvalidRewardIndex)?;
+        require!(
+            slot < MAX_BONUS_REWARDS && self.bonus_rewards[slot].is_configured(),
+            StakingError::InvalidRewardIndex
+        );
+        Ok(slot)
+    }
+
+    /// Advance a bonus reward's reward_per_token for a new distribution
+    pub fn distribute_bonus_reward(&mut self, slot: usize, new_rewards: u64) -> Result<()> {
+        let reward_increase = self.reward_per_token_increase(new_rewards)?;
+
+        let bonus = &mut self.bonus_rewards[slot];
+        bonus.reward_per_token = bonus
+            .reward_per_token
+            .checked_add(reward_increase)
+            .ok_or(StakingError::MathOverflow)?;
+        bonus.total_distributed = bonus
+            .total_distributed
+            .checked_add(new_rewards)
+            .ok_or(StakingError::MathOverflow)?;
+        bonus.total_owed = bonus
+            .total_owed
+            .checked_add(new_rewards)
+            .ok_or(StakingError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Current reward_per_token of every bonus slot
+    pub fn bonus_rewards_per_token(&self) -> [u128; MAX_BONUS_REWARDS] {
+        self.bonus_rewards.map(|bonus| bonus.reward_per_token)
+    }
+
+    /// Forfeit rewards a staker accrued before becoming eligible for them
+    ///
+    /// A position is eligible once `min_stake_duration_for_rewards` has passed
+    /// since its last stake change. Must be called before the staker's
+    /// rewards are banked or claimed: if the staker is not yet eligible, the
+    /// fresh accrual since their last stake change is forfeited and rolled
+    /// into reward_per_token for the other stakers. Rewards banked earlier
+    /// are not affected. Bonus rewards are forfeited the same way. Returns
+    /// the forfeited primary reward amount.
+    pub fn forfeit_ineligible_rewards(&mut self, staker: &mut Staker, now: i64) -> Result<u64> {
+        if staker.is_reward_eligible(now, self.min_stake_duration_for_rewards) {
+            return Ok(0);
+        }
+
+        let forfeited = staker.calculate_pending_rewards(self.reward_per_token)?;
+        self.redistribute_forfeited_rewards(forfeited, staker.effective_stake)?;
+
+        // The staker keeps their position, so move them past the increase
+        staker.update_reward_debt(self.reward_per_token);
+
+        for slot in 0..MAX_BONUS_REWARDS {
+            let bonus_rpt = self.bonus_rewards[slot].reward_per_token;
+            let bonus_forfeited = staker.calculate_bonus_pending(slot, bonus_rpt)?;
+            self.redistribute_forfeited_bonus(slot, bonus_forfeited, staker.effective_stake)?;
+            staker.bonus_reward_debt[slot] = self.bonus_rewards[slot].reward_per_token;
+        }
+
+        Ok(forfeited)
+    }
+
+    /// Forfeit everything a staker has accrued, banked or fresh, on every
+    /// reward
+    ///
+    /// Used by emergency_unstake. The rewards roll into reward_per_token for
+    /// the other stakers the same way as `forfeit_ineligible_rewards`.
+    /// Returns the forfeited primary reward amount.
+    pub fn forfeit_all_rewards(&mut self, staker: &mut Staker) -> Result<u64> {
+        let forfeited = staker.forfeit_pending_rewards(self.reward_per_token)?;
+        self.redistribute_forfeited_rewards(forfeited, staker.effective_stake)?;
+        staker.update_reward_debt(self.reward_per_token);
+
+        for slot in 0..MAX_BONUS_REWARDS {
+            let bonus_rpt = self.bonus_rewards[slot].reward_per_token;
+            let bonus_forfeited = staker.bonus_claimable(slot, bonus_rpt)?;
+            self.redistribute_forfeited_bonus(slot, bonus_forfeited, staker.effective_stake)?;
+            staker.bonus_pending_unclaimed[slot] = 0;
+            staker.bonus_reward_debt[slot] = self.bonus_rewards[slot].reward_per_token;
+        }
+
+        Ok(forfeited)
+    }
+
+    /// Roll a forfeited bonus reward back into its reward_per_token
+    ///
+    /// Same rules as `redistribute_forfeited_rewards`.
+    fn redistribute_forfeited_bonus(&mut self, slot: usize, amount: u64, excluded_stake: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let remaining_stake = self
+            .total_effective_stake
+            .checked_sub(excluded_stake)
+            .ok_or(StakingError::MathUnderflow)?;
+
+        let bonus = &mut self.bonus_rewards[slot];
+        if remaining_stake == 0 {
+            bonus.total_owed = bonus.total_owed.saturating_sub(amount);
+            return Ok(());
+        }
+
+        let reward_increase = (amount as u128)
+            .checked_mul(REWARD_PRECISION)
+            .ok_or(StakingError::MathOverflow)?
+            .checked_div(remaining_stake as u128)
+            .ok_or(StakingError::DivisionByZero)?;
+
+        bonus.reward_per_token = bonus
+            .reward_per_token
+            .checked_add(reward_increase)
+            .ok_or(StakingError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Current launch boost multiplier in basis points
+    ///
+    /// Decays linearly from boost_multiplier_bps at boost_start_time to 1x at
+    /// boost_end_time. Returns 1x when no boost is configured.
+    pub fn current_boost_bps(&self, now: i64) -> u64 {
+        let max_bps = self.boost_multiplier_bps as u64;
+        if max_bps <= BOOST_BPS_DENOMINATOR || now >= self.boost_end_time {
+            return BOOST_BPS_DENOMINATOR;
+        }
+
+        let duration = (self.boost_end_time - self.boost_start_time).max(1) as u128;
+        let remaining = (self.boost_end_time - now.max(self.boost_start_time)) as u128;
+        let extra = (max_bps - BOOST_BPS_DENOMINATOR) as u128 * remaining / duration;
+
+        BOOST_BPS_DENOMINATOR + extra as u64
+    }
+
+    /// Recompute a staker's effective stake at the current launch boost and
+    /// lock multiplier, and keep total_effective_stake in sync
+    ///
+    /// The staker's pending rewards must be banked first, since accrual is
+    /// computed on the old effective stake. Bonus rewards are banked here.
+    pub fn reweight_staker(&mut self, staker: &mut Staker, now: i64) -> Result<()> {
+        staker.bank_bonus_rewards(self.bonus_rewards_per_token())?;
+
+        // Launch boost and lock multiplier stack
+        let new_effective = (staker.staked_amount as u128)
+            .checked_mul(self.current_boost_bps(now) as u128)
+            .ok_or(StakingError::MathOverflow)?
+            .checked_mul(staker.lock_boost_at(now) as u128)
+            .ok_or(StakingError::MathOverflow)?
+            .checked_div((BOOST_BPS_DENOMINATOR * BOOST_BPS_DENOMINATOR) as u128)
+            .ok_or(StakingError::DivisionByZero)?;
+        let new_effective = u64::try_from(new_effective).map_err(|_| StakingError::MathOverflow)?;
+
+        self.total_effective_stake = self
+            .total_effective_stake
+            .checked_sub(staker.effective_stake)
+            .ok_or(StakingError::MathUnderflow)?
+            .checked_add(new_effective)
+            .ok_or(StakingError::MathOverflow)?;
+        staker.effective_stake = new_effective;
+
+        Ok(())
+    }
+
+    /// Record rewards paid out to a staker
+    ///
+    /// Saturates at zero so a pool migrated from the original layout, which
+    /// starts with total_rewards_owed at 0, can still pay out claims.
+    pub fn record_rewards_paid(&mut self, amount: u64) {
+        self.total_rewards_owed = self.total_rewards_owed.saturating_sub(amount);
+    }
+
+    /// Amount by which outstanding rewards exceed the reward vault balance
+    pub fn reward_shortfall(&self, reward_vault_balance: u64) -> u64 {
+        self.total_rewards_owed.saturating_sub(reward_vault_balance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_distribute_scales_with_decimals() {
+        assert_eq!(StakingPool::min_distribute_for_decimals(6).unwrap(), 1_000);
+        assert_eq!(StakingPool::min_distribute_for_decimals(8).unwrap(), 100_000);
+        assert_eq!(StakingPool::min_distribute_for_decimals(9).unwrap(), 1_000_000);
+        assert_eq!(StakingPool::min_distribute_for_decimals(2).unwrap(), 1);
+        assert_eq!(StakingPool::min_distribute_for_decimals(0).unwrap(), 1);
+        assert!(StakingPool::min_distribute_for_decimals(255).is_err());
+    }
+
+    #[test]
+    fn reward_per_token_increase_is_bounded_at_stake_extremes() {
+        use crate::constants::{MAX_STAKE_AMOUNT, MIN_STAKE_AMOUNT};
+
+        let pool_with_stake = |total_effective_stake| StakingPool {
+            total_effective_stake,
+            ..StakingPool::default()
+        };
+
+        // Smallest stake that may receive the largest distribution
+        assert_eq!(
+            pool_with_stake(MIN_STAKE_AMOUNT)
+                .reward_per_token_increase(MAX_REWARD_PER_DISTRIBUTION)
+                .unwrap(),
+            MAX_REWARD_PER_TOKEN_INCREASE
+        );
+        // Dust stake left behind by a partial unstake
+        assert_eq!(
+            pool_with_stake(MIN_STAKE_AMOUNT - 1)
+                .reward_per_token_increase(MAX_REWARD_PER_DISTRIBUTION)
+                .unwrap_err(),
+            error!(StakingError::RewardPerTokenIncreaseExceedsMax)
+        );
+        assert_eq!(
+            pool_with_stake(1).reward_per_token_increase(100_000_000).unwrap_err(),
+            error!(StakingError::RewardPerTokenIncreaseExceedsMax)
+        );
+        // Max stake with generous headroom for stacked multipliers still sees
+        // a non-zero increase from a single base unit
+        assert!(pool_with_stake(MAX_STAKE_AMOUNT * 10).reward_per_token_increase(1).unwrap() > 0);
+        assert_eq!(
+            pool_with_stake(u64::MAX).reward_per_token_increase(1).unwrap_err(),
+            error!(StakingError::InvalidAmount)
+        );
+    }
+
+    #[test]
+    fn distribution_needs_effective_stake_not_just_total_staked() {
+        let mut pool = StakingPool {
+            total_staked: 10_000,
+            total_effective_stake: 0,
+            ..StakingPool::default()
+        };
+
+        assert_eq!(
+            pool.update_reward_per_token(1_000).unwrap_err(),
+            error!(StakingError::NoStakersToDistribute)
+        );
+        assert_eq!(pool.reward_per_token, 0);
+        assert_eq!(pool.total_rewards_distributed, 0);
+        assert_eq!(pool.total_rewards_owed, 0);
+    }
+
+    #[test]
+    fn only_admin_and_configured_distributor_can_distribute() {
+        let admin = Pubkey::new_unique();
+        let bot = Pubkey::new_unique();
+        let mut pool = StakingPool {
+            admin,
+            ..StakingPool::default()
+        };
+        assert!(pool.can_distribute(&admin));
+        assert!(!pool.can_distribute(&bot));
+        assert!(!pool.can_distribute(&Pubkey::default()));
+
+        pool.distributor = bot;
+        assert!(pool.can_distribute(&admin));
+        assert!(pool.can_distribute(&bot));
+        assert!(!pool.can_distribute(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn forfeited_rewards_raise_remaining_stakers_claimable() {
+        let mut pool = StakingPool {
+            total_effective_stake: 400,
+            reward_per_token: 2 * REWARD_PRECISION,
+            ..StakingPool::default()
+        };
+        let mut leaving = Staker {
+            staked_amount: 100,
+            effective_stake: 100,
+            pending_unclaimed: 100,
+            ..Staker::default()
+        };
+        let staying = Staker {
+            staked_amount: 300,
+            effective_stake: 300,
+            ..Staker::default()
+        };
+        assert_eq!(staying.total_claimable(pool.reward_per_token).unwrap(), 600);
+
+        // 100 banked + 200 fresh are forfeited by the leaving staker
+        let forfeited = leaving.forfeit_pending_rewards(pool.reward_per_token).unwrap();
+        assert_eq!(forfeited, 300);
+        pool.redistribute_forfeited_rewards(forfeited, leaving.effective_stake).unwrap();
+        // The caller moves the excluded stake past the increase
+        leaving.update_reward_debt(pool.reward_per_token);
+
+        assert_eq!(pool.total_forfeited, 300);
+        assert_eq!(leaving.total_claimable(pool.reward_per_token).unwrap(), 0);
+        assert_eq!(staying.total_claimable(pool.reward_per_token).unwrap(), 900);
+
+        // Nothing to forfeit is a no-op
+        let before = pool.reward_per_token;
+        pool.redistribute_forfeited_rewards(0, 0).unwrap();
+        assert_eq!(pool.reward_per_token, before);
+        assert_eq!(pool.total_forfeited, 300);
+    }
+
+    #[test]
+    fn owed_tracks_forfeits_claims_and_shortfall() {
+        let mut pool = StakingPool {
+            total_effective_stake: 100,
+            total_rewards_owed: 1_000,
+            ..StakingPool::default()
+        };
+
+        // Redistributed forfeits stay owed to the remaining stakers
+        pool.redistribute_forfeited_rewards(200, 40).unwrap();
+        assert_eq!(pool.total_rewards_owed, 1_000);
+
+        // Forfeits with no remaining stakers are no longer owed
+        pool.redistribute_forfeited_rewards(300, 100).unwrap();
+        assert_eq!(pool.total_rewards_owed, 700);
+
+        pool.record_rewards_paid(200);
+        assert_eq!(pool.total_rewards_owed, 500);
+        assert_eq!(pool.reward_shortfall(600), 0);
+        assert_eq!(pool.reward_shortfall(350), 150);
+
+        // Saturates instead of failing the claim
+        pool.record_rewards_paid(10_000);
+        assert_eq!(pool.total_rewards_owed, 0);
+    }
+
+    #[test]
+    fn legacy_pool_migrates_to_current_layout() {
+        let original = StakingPool {
+            admin: Pubkey::new_unique(),
+            total_staked: 5_000,
+            reward_per_token: 7 * REWARD_PRECISION,
+            staker_count: 3,
+            bump: 254,
+            stake_vault_bump: 253,
+            ..StakingPool::default()
+        };
+
+        // The original layout: every field up to stake_vault_bump, then padding
+        let mut data = Vec::new();
+        original.try_serialize(&mut data).unwrap();
+        data.truncate(StakingPool::LEGACY_SIZE - 64);
+        data.resize(StakingPool::LEGACY_SIZE, 0);
+        assert!(StakingPool::try_deserialize(&mut &data[..]).is_err());
+
+        // migrate_staking_pool zero-extends the account before reading it
+        data.resize(StakingPool::SIZE, 0);
+        let mut pool = StakingPool::try_deserialize(&mut &data[..]).unwrap();
+        pool.migrate_from_legacy(6).unwrap();
+
+        assert_eq!(pool.admin, original.admin);
+        assert_eq!(pool.reward_per_token, original.reward_per_token);
+        assert_eq!((pool.bump, pool.stake_vault_bump), (254, 253));
+        assert_eq!(pool.total_effective_stake, 5_000);
+        assert_eq!(pool.min_distribute, 1_000);
+        assert_eq!(pool.boost_multiplier_bps, 0);
+
+        // The migrated pool fits the grown account
+        let mut out = Vec::new();
+        pool.try_serialize(&mut out).unwrap();
+        assert!(out.len() <= StakingPool::SIZE);
+    }
+
+    #[test]
+    fn boost_decays_linearly_to_one_x() {
+        let pool = StakingPool {
+            boost_multiplier_bps: 20_000,
+            boost_start_time: 1_000,
+            boost_end_time: 2_000,
+            ..StakingPool::default()
+        };
+
+        assert_eq!(pool.current_boost_bps(1_000), 20_000);
+        assert_eq!(pool.current_boost_bps(1_500), 15_000);
+        assert_eq!(pool.current_boost_bps(2_000), 10_000);
+        assert_eq!(pool.current_boost_bps(5_000), 10_000);
+
+        // No boost configured
+        assert_eq!(StakingPool::default().current_boost_bps(0), 10_000);
+    }
+
+    #[test]
+    fn reweight_keeps_total_effective_stake_in_sync() {
+        let mut pool = StakingPool {
+            boost_multiplier_bps: 20_000,
+            boost_start_time: 0,
+            boost_end_time: 100,
+            ..StakingPool::default()
+        };
+        let mut early = Staker {
+            staked_amount: 100,
+            ..Staker::default()
+        };
+        let mut late = Staker {
+            staked_amount: 100,
+            ..Staker::default()
+        };
+
+        pool.reweight_staker(&mut early, 0).unwrap();
+        pool.reweight_staker(&mut late, 100).unwrap();
+        assert_eq!(early.effective_stake, 200);
+        assert_eq!(late.effective_stake, 100);
+        assert_eq!(pool.total_effective_stake, 300);
+
+        // After the boost ends, reweighting the early staker drops them to 1x
+        pool.reweight_staker(&mut early, 150).unwrap();
+        assert_eq!(early.effective_stake, 100);
+        assert_eq!(pool.total_effective_stake, 200);
+
+        // A full exit removes the staker's weight entirely
+        early.staked_amount = 0;
+        pool.reweight_staker(&mut early, 150).unwrap();
+        assert_eq!(pool.total_effective_stake, 100);
+    }
+
+    #[test]
+    fn ineligible_accrual_rolls_to_other_stakers() {
+        let mut pool = StakingPool {
+            total_effective_stake: 200,
+            total_rewards_owed: 2_000,
+            reward_per_token: 10 * REWARD_PRECISION,
+            min_stake_duration_for_rewards: 100,
+            ..StakingPool::default()
+        };
+        // Staked at t=1_000 with rpt at 5, earning 5 per token since
+        let mut sniper = Staker {
+            staked_amount: 100,
+            effective_stake: 100,
+            reward_debt: 5 * REWARD_PRECISION,
+            pending_unclaimed: 50,
+            last_stake_time: 1_000,
+            ..Staker::default()
+        };
+        let mut holder = Staker {
+            staked_amount: 100,
+            effective_stake: 100,
+            reward_debt: 10 * REWARD_PRECISION,
+            last_stake_time: 0,
+            ..Staker::default()
+        };
+
+        // Long-standing staker is eligible, nothing forfeited
+        assert_eq!(pool.forfeit_ineligible_rewards(&mut holder, 1_050).unwrap(), 0);
+
+        // Fresh accrual is forfeited, earlier banked rewards are kept
+        assert_eq!(pool.forfeit_ineligible_rewards(&mut sniper, 1_050).unwrap(), 500);
+        assert_eq!(sniper.total_claimable(pool.reward_per_token).unwrap(), 50);
+        assert_eq!(holder.total_claimable(pool.reward_per_token).unwrap(), 500);
+        assert_eq!(pool.total_forfeited, 500);
+
+        // Once the duration has passed the accrual is kept
+        pool.reward_per_token += REWARD_PRECISION;
+        assert_eq!(pool.forfeit_ineligible_rewards(&mut sniper, 1_100).unwrap(), 0);
+        assert_eq!(sniper.total_claimable(pool.reward_per_token).unwrap(), 150);
+    }
+
+    #[test]
+    fn forfeit_all_rewards_rolls_banked_and_fresh_to_other_stakers() {
+        let mut pool = StakingPool {
+            total_effective_stake: 200,
+            total_rewards_owed: 2_000,
+            reward_per_token: 10 * REWARD_PRECISION,
+            ..StakingPool::default()
+        };
+        pool.bonus_rewards[0].mint = Pubkey::new_unique();
+        pool.bonus_rewards[0].reward_per_token = 2 * REWARD_PRECISION;
+        let mut leaver = Staker {
+            staked_amount: 100,
+            effective_stake: 100,
+            reward_debt: 5 * REWARD_PRECISION,
+            pending_unclaimed: 50,
+            ..Staker::default()
+        };
+        leaver.bonus_pending_unclaimed[0] = 30;
+        let holder = Staker {
+            staked_amount: 100,
+            effective_stake: 100,
+            reward_debt: 10 * REWARD_PRECISION,
+            ..Staker::default()
+        };
+
+        // 50 banked + 500 fresh primary, 30 banked + 200 fresh bonus
+        assert_eq!(pool.forfeit_all_rewards(&mut leaver).unwrap(), 550);
+        assert_eq!(leaver.total_claimable(pool.reward_per_token).unwrap(), 0);
+        assert_eq!(leaver.bonus_claimable(0, pool.bonus_rewards[0].reward_per_token).unwrap(), 0);
+        assert_eq!(holder.total_claimable(pool.reward_per_token).unwrap(), 550);
+        assert_eq!(holder.bonus_claimable(0, pool.bonus_rewards[0].reward_per_token).unwrap(), 430);
+        assert_eq!(pool.total_forfeited, 550);
+        assert_eq!(pool.total_rewards_owed, 2_000);
+    }
+
+    #[test]
+    fn bonus_rewards_accrue_independently_and_survive_reweights() {
+        let mut pool = StakingPool::default();
+        pool.bonus_rewards[0].mint = Pubkey::new_unique();
+        assert!(pool.bonus_slot(0).is_err());
+        assert_eq!(pool.bonus_slot(1).unwrap(), 0);
+        assert!(pool.bonus_slot(2).is_err()); // not configured
+
+        let mut staker = Staker {
+            staked_amount: 100,
+            ..Staker::default()
+        };
+        pool.reweight_staker(&mut staker, 0).unwrap();
+
+        pool.distribute_bonus_reward(0, 1_000).unwrap();
+        assert_eq!(pool.bonus_rewards[0].total_owed, 1_000);
+        assert_eq!(staker.bonus_claimable(0, pool.bonus_rewards[0].reward_per_token).unwrap(), 1_000);
+        assert_eq!(staker.total_claimable(pool.reward_per_token).unwrap(), 0);
+
+        // A stake change banks the bonus before the weight moves
+        staker.staked_amount = 0;
+        pool.reweight_staker(&mut staker, 0).unwrap();
+        assert_eq!(staker.bonus_pending_unclaimed[0], 1_000);
+        assert_eq!(staker.bonus_claimable(0, pool.bonus_rewards[0].reward_per_token).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn streamed_rewards_accrue_linearly_and_roll_over() {
+        let mut pool = StakingPool {
+            total_effective_stake: 100,
+            ..StakingPool::default()
+        };
+        assert!(pool.start_reward_stream(1_000, 0, 0).is_err());
+        assert!(pool.start_reward_stream(1_000, MAX_REWARD_DURATION_SECONDS + 1, 0).is_err());
+
+        pool.start_reward_stream(1_000, 100, 0).unwrap();
+        pool.accrue_streamed_rewards(25).unwrap();
+        assert_eq!(pool.total_rewards_owed, 250);
+        assert_eq!(pool.reward_per_token, 25 * REWARD_PRECISION / 10);
+
+        // Time with nobody staked is skipped
+        pool.total_effective_stake = 0;
+        pool.accrue_streamed_rewards(50).unwrap();
+        assert_eq!(pool.total_rewards_owed, 250);
+        pool.total_effective_stake = 100;
+
+        // Topping up mid-stream rolls the unstreamed 500 into the new stream
+        pool.start_reward_stream(500, 100, 50).unwrap();
+        pool.accrue_streamed_rewards(1_000).unwrap();
+        assert_eq!(pool.total_rewards_owed, 1_250);
+        assert_eq!(pool.reward_last_update_time, 150);
+    }
 }
@@ -24,6 +24,14 @@ pub const STAKING_FEE_BPS: u16 = 1500;
 /// This funds development, audits, and operational costs
 pub const TREASURY_FEE_BPS: u16 = 500;
 
+/// Maximum entry fee on deposits: 1%
+/// Separate from the profit split above; sent to the treasury
+pub const MAX_DEPOSIT_FEE_BPS: u16 = 100;
+
+/// Maximum exit fee on withdrawals: 1%
+/// Kept in the vault for the remaining depositors
+pub const MAX_WITHDRAWAL_FEE_BPS: u16 = 100;
+
 /// Total basis points (100%) - used as denominator in fee calculations
 /// Example: fee = amount * FEE_BPS / BPS_DENOMINATOR
 pub const BPS_DENOMINATOR: u16 = 10000;
@@ -40,6 +48,10 @@ pub const USDC_DECIMALS: u8 = 6;
 /// This simplifies share price calculations
 pub const SHARE_DECIMALS: u8 = 6;
 
+/// Fixed-point scale for share prices (deposit tokens per share)
+/// 1_000_000 = 1.0, i.e. one base unit of deposit token per share base unit
+pub const SHARE_PRICE_PRECISION: u64 = 1_000_000;
+
 // =============================================================================
 // PDA SEEDS
 // =============================================================================
@@ -63,8 +75,35 @@ pub const SHARE_MINT_SEED: &[u8] = b"share_mint";
 /// Full seed: ["depositor", pool_pubkey, owner_pubkey]
 pub const DEPOSITOR_SEED: &[u8] = b"depositor";
 
+// =============================================================================
+// DEPOSITOR HISTORY
+// =============================================================================
+
+/// Number of recent withdrawals kept on each Depositor account
+/// Kept small to bound rent: each entry is 16 bytes
+pub const WITHDRAWAL_HISTORY_LEN: usize = 8;
+
 // NOTE: OPERATOR_SEED has been REMOVED - no external operators in new design
 
+// =============================================================================
+// PAUSE FLAGS
+// =============================================================================
+// Bits of Pool.pause_flags, set with set_pause_flags. Each stops one kind of
+// operation; is_paused (pause_pool) still stops all of them at once. Both
+// pause_pool and PAUSE_WITHDRAWALS start the emergency withdrawal clock.
+
+/// Blocks deposit
+pub const PAUSE_DEPOSITS: u8 = 1 << 0;
+
+/// Blocks withdraw and withdraw_no_record
+pub const PAUSE_WITHDRAWALS: u8 = 1 << 1;
+
+/// Blocks record_profit (liquidation profit recording)
+pub const PAUSE_LIQUIDATIONS: u8 = 1 << 2;
+
+/// Every valid pause flag
+pub const PAUSE_ALL: u8 = PAUSE_DEPOSITS | PAUSE_WITHDRAWALS | PAUSE_LIQUIDATIONS;
+
 // =============================================================================
 // SAFETY LIMITS
 // =============================================================================
@@ -82,6 +121,7 @@ pub const MIN_DEPOSIT_AMOUNT: u64 = 1_000_000;
 /// 1. Deposits 1 token, gets 1 share
 /// 2. Transfers tokens directly to vault
 /// 3. Inflates share price, causing next depositor to get ~0 shares
+///
 /// By requiring a large first deposit, this attack becomes economically unviable
 pub const MIN_FIRST_DEPOSIT: u64 = 1_000_000_000; // 1000 USDC
 
@@ -98,6 +138,11 @@ pub const DEFAULT_POOL_SIZE: u64 = 500_000_000_000;
 /// Hard limit - even admin cannot set pool cap above this
 pub const MAX_POOL_SIZE: u64 = 1_000_000_000_000_000;
 
+/// Maximum total share supply (1T shares = 1,000,000,000,000 * 10^6)
+/// Keeps total_shares well below u64::MAX so share math intermediates
+/// (deposit * total_shares) stay bounded even after long profit accrual
+pub const MAX_TOTAL_SHARES: u64 = 1_000_000_000_000_000_000;
+
 // =============================================================================
 // SECURITY: TIMELOCK CONFIGURATION (FIX-4, FIX-5, FIX-6, FIX-7)
 // =============================================================================
@@ -110,6 +155,11 @@ pub const ADMIN_TIMELOCK_SECONDS: i64 = 86400; // 24 hours
 /// Users can emergency withdraw after pool has been paused this long
 pub const EMERGENCY_TIMELOCK_SECONDS: i64 = 604800; // 7 days
 
+/// Emergency drain timelock (14 days in seconds)
+/// Longer than EMERGENCY_TIMELOCK_SECONDS so depositors get a full week of
+/// emergency withdrawals before the admin and guardian can drain the vault
+pub const EMERGENCY_DRAIN_TIMELOCK_SECONDS: i64 = 1209600; // 14 days
+
 /// Maximum time a pending change can stay pending before expiring (7 days)
 /// If not finalized within this time, the proposal expires and must be re-submitted
 pub const PENDING_CHANGE_EXPIRY_SECONDS: i64 = 604800; // 7 days
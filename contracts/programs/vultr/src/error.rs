@@ -205,4 +205,119 @@ pub enum VultrError {
     /// Invalid address provided (e.g., zero address)
     #[msg("Invalid address - cannot be zero address")]
     InvalidAddress,
+
+    // =========================================================================
+    // Incident Response Errors (6100-6109)
+    // =========================================================================
+
+    /// The bot wallet was revoked in an emergency - a new bot must be
+    /// proposed and finalized through the normal timelock
+    #[msg("Bot wallet has been revoked")]
+    BotWalletRevoked,
+
+    /// Pool was emergency-drained and is permanently disabled
+    #[msg("Pool has been emergency drained and is permanently disabled")]
+    PoolDisabled,
+
+    // =========================================================================
+    // Depositor Account Errors (6110-6119)
+    // =========================================================================
+
+    /// Depositor still holds shares - stats can only be reset after a full exit
+    #[msg("Depositor still holds shares")]
+    SharesOutstanding,
+
+    // =========================================================================
+    // Share Price Sanity Errors (6130-6139)
+    // =========================================================================
+
+    /// Share price is outside the configured sanity band
+    #[msg("Share price outside sanity band - pool accounting looks wrong")]
+    SharePriceSanityFailed,
+
+    /// Sanity band lower bound is above its upper bound
+    #[msg("Invalid share price band - min must not exceed max")]
+    InvalidSharePriceBand,
+
+    // =========================================================================
+    // Withdrawal Fee Errors (6140-6149)
+    // =========================================================================
+
+    /// Proposed withdrawal fee is above MAX_WITHDRAWAL_FEE_BPS
+    #[msg("Withdrawal fee exceeds maximum")]
+    WithdrawalFeeExceedsMax,
+
+    // =========================================================================
+    // User Deposit Cap Errors (6150-6159)
+    // =========================================================================
+
+    /// Depositor's cumulative deposits would exceed max_deposit_per_user
+    #[msg("Deposit would exceed the per-user deposit cap")]
+    ExceedsUserDepositCap,
+
+    // =========================================================================
+    // Token Extension Errors (6160-6169)
+    // =========================================================================
+
+    /// Deposit mint carries a Token-2022 extension the pool cannot support
+    /// (a transfer hook with a program or authority, or non-transferable tokens)
+    #[msg("Deposit mint uses an unsupported token extension")]
+    UnsupportedMintExtension,
+
+    // =========================================================================
+    // Admin Transfer Errors (6170-6179)
+    // =========================================================================
+
+    /// finalize_admin_transfer was not signed by the pending admin
+    #[msg("The pending admin must sign to finalize the admin transfer")]
+    PendingAdminMustSign,
+
+    // =========================================================================
+    // Pause Flag Errors (6180-6189)
+    // =========================================================================
+
+    /// set_pause_flags was given bits outside PAUSE_ALL
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+
+    // =========================================================================
+    // Pool Cap Errors (6190-6199)
+    // =========================================================================
+
+    /// update_pool_cap was asked to raise the cap, which needs the timelock
+    #[msg("Raising the pool cap requires propose_pool_cap and a 24h timelock")]
+    PoolCapIncreaseRequiresTimelock,
+
+    // =========================================================================
+    // Profit Errors (6200-6209)
+    // =========================================================================
+
+    /// record_profit was called with less than pool.min_profit_amount
+    #[msg("Profit is below the pool's minimum profit amount")]
+    ProfitBelowMinimum,
+
+    // =========================================================================
+    // Depositor Share Account Errors (6210-6219)
+    // =========================================================================
+
+    /// Share account passed for a zero-balance check is not the owner's
+    /// associated token account for the share mint
+    #[msg("Share account must be the owner's associated token account")]
+    ShareAccountNotAssociated,
+
+    // =========================================================================
+    // Guardian Errors (6220-6229)
+    // =========================================================================
+
+    /// The guardian co-signs emergency_drain, so it must be a separate key
+    #[msg("Guardian and admin must be different keys")]
+    GuardianCannotBeAdmin,
+
+    // =========================================================================
+    // Replay Protection Errors (6230-6239)
+    // =========================================================================
+
+    /// Expected nonce does not match pool.nonce (duplicate or out-of-order submission)
+    #[msg("Stale nonce - instruction was already applied or submitted out of order")]
+    StaleNonce,
 }
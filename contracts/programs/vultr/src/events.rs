@@ -0,0 +1,76 @@
+// =============================================================================
+// Events
+// =============================================================================
+// Emitted with emit! so indexers and monitoring can follow pool activity
+// from transaction logs without polling account state.
+// =============================================================================
+
+use anchor_lang::prelude::*;
+
+/// Emitted by deposit
+#[event]
+pub struct DepositEvent {
+    /// The pool deposited into
+    pub pool: Pubkey,
+
+    /// The user who deposited
+    pub depositor: Pubkey,
+
+    /// Gross deposit tokens paid by the user (including deposit_fee)
+    pub amount: u64,
+
+    /// Portion of amount sent to the treasury
+    pub deposit_fee: u64,
+
+    /// Share tokens minted
+    pub shares_minted: u64,
+
+    /// Share price after the deposit (SHARE_PRICE_PRECISION scale)
+    pub share_price: u64,
+
+    /// Unix timestamp of the deposit
+    pub timestamp: i64,
+}
+
+/// Emitted by withdraw and withdraw_no_record
+#[event]
+pub struct WithdrawEvent {
+    /// The pool withdrawn from
+    pub pool: Pubkey,
+
+    /// The user who withdrew
+    pub withdrawer: Pubkey,
+
+    /// Share tokens burned
+    pub shares_burned: u64,
+
+    /// Deposit tokens paid out
+    pub amount: u64,
+
+    /// Share price after the withdrawal (SHARE_PRICE_PRECISION scale)
+    pub share_price: u64,
+
+    /// Unix timestamp of the withdrawal
+    pub timestamp: i64,
+}
+
+/// Emitted by emergency_withdraw
+/// Kept separate from normal withdrawals so panic outflows during an
+/// incident can be tracked on their own
+#[event]
+pub struct EmergencyWithdrawEvent {
+    /// The pool withdrawn from
+    pub pool: Pubkey,
+
+    /// The user who withdrew
+    pub user: Pubkey,
+
+    /// Share tokens burned
+    pub shares_burned: u64,
+
+    /// Deposit tokens paid out
+    pub amount: u64,
+
+    /// Unix timestamp of the withdrawal
+    pub timestamp: i64,
+}
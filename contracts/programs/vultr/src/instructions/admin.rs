@@ -44,6 +44,9 @@ pub fn handler_pause_pool(ctx: Context<PausePool>, paused: bool) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
 
+    // A drained pool can never be unpaused
+    require!(paused || !pool.is_disabled, VultrError::PoolDisabled);
+
     if pool.is_paused == paused {
         msg!(
             "Pool is already {}",
@@ -52,15 +55,24 @@ pub fn handler_pause_pool(ctx: Context<PausePool>, paused: bool) -> Result<()> {
         return Ok(());
     }
 
+    let withdrawals_were_paused = pool.withdrawals_paused();
     pool.is_paused = paused;
 
-    // SECURITY FIX-6: Track when pool was paused for emergency withdrawal
+    // SECURITY FIX-6: Track when withdrawals stopped for emergency withdrawal.
+    // A PAUSE_WITHDRAWALS flag that is already set keeps its earlier clock.
+    // The drain clock always starts at the full pause.
     if paused {
-        pool.pause_timestamp = clock.unix_timestamp;
+        if !withdrawals_were_paused {
+            pool.pause_timestamp = clock.unix_timestamp;
+        }
+        pool.full_pause_timestamp = clock.unix_timestamp;
         msg!("Pool PAUSED at timestamp {}. Emergency withdrawals available after {} seconds.",
             pool.pause_timestamp, EMERGENCY_TIMELOCK_SECONDS);
     } else {
-        pool.pause_timestamp = 0;
+        if !pool.withdrawals_paused() {
+            pool.pause_timestamp = 0;
+        }
+        pool.full_pause_timestamp = 0;
         msg!("Pool UNPAUSED");
     }
 
@@ -73,6 +85,45 @@ pub fn handler_pause_pool(ctx: Context<PausePool>, paused: bool) -> Result<()> {
     Ok(())
 }
 
+// =============================================================================
+// Granular Pause Flags
+// =============================================================================
+// Lets the admin stop deposits or profit recording while users can still
+// withdraw. No timelock: like pause_pool, this is an incident response tool.
+// PAUSE_WITHDRAWALS starts the emergency withdrawal clock just like pause_pool,
+// so a flag-only pause cannot lock depositors in indefinitely.
+
+/// Set the granular pause flags (admin only)
+///
+/// `pause_flags` replaces the current bits; pass 0 to clear them all.
+pub fn handler_set_pause_flags(ctx: Context<PausePool>, pause_flags: u8) -> Result<()> {
+    require!(pause_flags & !PAUSE_ALL == 0, VultrError::InvalidPauseFlags);
+
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+    let old_flags = pool.pause_flags;
+    let withdrawals_were_paused = pool.withdrawals_paused();
+    pool.pause_flags = pause_flags;
+
+    // SECURITY FIX-6: Start or stop the emergency withdrawal clock
+    if !withdrawals_were_paused && pool.withdrawals_paused() {
+        pool.pause_timestamp = clock.unix_timestamp;
+    } else if withdrawals_were_paused && !pool.withdrawals_paused() {
+        pool.pause_timestamp = 0;
+    }
+
+    msg!(
+        "Pause flags updated: {:#05b} -> {:#05b} (deposits={}, withdrawals={}, liquidations={})",
+        old_flags,
+        pause_flags,
+        pool.deposits_paused(),
+        pool.withdrawals_paused(),
+        pool.liquidations_paused()
+    );
+
+    Ok(())
+}
+
 // =============================================================================
 // SECURITY FIX-4: Admin Transfer with Timelock
 // =============================================================================
@@ -111,6 +162,9 @@ pub fn handler_propose_admin_transfer(ctx: Context<ProposeAdminTransfer>) -> Res
     // Validate not transferring to self
     require!(new_admin != pool.admin, VultrError::InvalidAuthority);
 
+    // Admin and guardian are the two drain signers
+    require!(new_admin != pool.guardian, VultrError::GuardianCannotBeAdmin);
+
     // Set pending admin and timestamp
     pool.pending_admin = new_admin;
     pool.admin_change_timestamp = clock.unix_timestamp;
@@ -126,11 +180,9 @@ pub fn handler_propose_admin_transfer(ctx: Context<ProposeAdminTransfer>) -> Res
 /// Accounts required for finalize_admin_transfer instruction
 #[derive(Accounts)]
 pub struct FinalizeAdminTransfer<'info> {
-    /// The current admin must sign
-    #[account(
-        constraint = admin.key() == pool.admin @ VultrError::AdminOnly
-    )]
-    pub admin: Signer<'info>,
+    /// The pending admin must sign, proving it controls the new key
+    /// Checked against pool.pending_admin in the handler
+    pub new_admin: Signer<'info>,
 
     /// The pool to finalize admin transfer for
     #[account(
@@ -142,6 +194,9 @@ pub struct FinalizeAdminTransfer<'info> {
 }
 
 /// Finalize an admin transfer after timelock expires
+///
+/// Claim-based: signed by the incoming admin, so control can never pass to
+/// a key nobody holds.
 pub fn handler_finalize_admin_transfer(ctx: Context<FinalizeAdminTransfer>) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
@@ -149,6 +204,12 @@ pub fn handler_finalize_admin_transfer(ctx: Context<FinalizeAdminTransfer>) -> R
     // Check there's a pending change
     require!(pool.pending_admin != Pubkey::default(), VultrError::NoPendingChange);
 
+    // Only the proposed admin can claim
+    require!(
+        ctx.accounts.new_admin.key() == pool.pending_admin,
+        VultrError::PendingAdminMustSign
+    );
+
     // Check timelock has expired
     let elapsed = clock.unix_timestamp - pool.admin_change_timestamp;
     require!(elapsed >= ADMIN_TIMELOCK_SECONDS, VultrError::TimelockNotExpired);
@@ -156,6 +217,9 @@ pub fn handler_finalize_admin_transfer(ctx: Context<FinalizeAdminTransfer>) -> R
     // Check change hasn't expired (7 days max)
     require!(elapsed <= PENDING_CHANGE_EXPIRY_SECONDS, VultrError::TimelockExpired);
 
+    // The guardian may have changed since the proposal
+    require!(pool.pending_admin != pool.guardian, VultrError::GuardianCannotBeAdmin);
+
     // Apply the change
     let old_admin = pool.admin;
     pool.admin = pool.pending_admin;
@@ -169,8 +233,26 @@ pub fn handler_finalize_admin_transfer(ctx: Context<FinalizeAdminTransfer>) -> R
     Ok(())
 }
 
+/// Accounts required for cancel_admin_transfer instruction
+#[derive(Accounts)]
+pub struct CancelAdminTransfer<'info> {
+    /// The current admin must sign
+    #[account(
+        constraint = admin.key() == pool.admin @ VultrError::AdminOnly
+    )]
+    pub admin: Signer<'info>,
+
+    /// The pool to cancel the admin transfer for
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
 /// Cancel pending admin transfer
-pub fn handler_cancel_admin_transfer(ctx: Context<FinalizeAdminTransfer>) -> Result<()> {
+pub fn handler_cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
 
     require!(pool.pending_admin != Pubkey::default(), VultrError::NoPendingChangeToCancel);
@@ -292,6 +374,170 @@ pub fn handler_cancel_bot_wallet(ctx: Context<FinalizeBotWallet>) -> Result<()>
     Ok(())
 }
 
+// =============================================================================
+// Guardian & Emergency Bot Revocation
+// =============================================================================
+// The bot key is hot, so if it is compromised the team needs to cut it off
+// immediately rather than wait out the 24h bot wallet timelock. Revocation is
+// one-way: a replacement bot must still go through propose/finalize.
+//
+// Guardian changes themselves go through the same 24h timelock, because the
+// guardian is the second signer on emergency_drain.
+// =============================================================================
+
+/// Accounts required for propose_guardian instruction
+#[derive(Accounts)]
+pub struct ProposeGuardian<'info> {
+    /// The admin must sign
+    #[account(
+        constraint = admin.key() == pool.admin @ VultrError::AdminOnly
+    )]
+    pub admin: Signer<'info>,
+
+    /// The pool to update
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// The new guardian address
+    /// CHECK: This is just the new guardian address, we just store it
+    pub new_guardian: UncheckedAccount<'info>,
+}
+
+/// Propose a new guardian (starts 24h timelock)
+///
+/// The guardian co-signs emergency_drain, so an instant change would let the
+/// admin alone satisfy both signatures. The guardian must also differ from
+/// the admin.
+pub fn handler_propose_guardian(ctx: Context<ProposeGuardian>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let new_guardian = ctx.accounts.new_guardian.key();
+    let clock = Clock::get()?;
+
+    require!(new_guardian != Pubkey::default(), VultrError::InvalidAddress);
+    require!(new_guardian != pool.admin, VultrError::GuardianCannotBeAdmin);
+
+    pool.pending_guardian = new_guardian;
+    pool.guardian_change_timestamp = clock.unix_timestamp;
+
+    msg!("Guardian update PROPOSED by admin {}", ctx.accounts.admin.key());
+    msg!("New guardian will be: {}", new_guardian);
+    msg!("Timelock expires at: {} (in {} seconds)",
+        clock.unix_timestamp + ADMIN_TIMELOCK_SECONDS, ADMIN_TIMELOCK_SECONDS);
+
+    Ok(())
+}
+
+/// Accounts required for finalize_guardian and cancel_guardian instructions
+#[derive(Accounts)]
+pub struct FinalizeGuardian<'info> {
+    /// The admin must sign
+    #[account(
+        constraint = admin.key() == pool.admin @ VultrError::AdminOnly
+    )]
+    pub admin: Signer<'info>,
+
+    /// The pool to finalize guardian update for
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Finalize a guardian update after timelock expires
+pub fn handler_finalize_guardian(ctx: Context<FinalizeGuardian>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(pool.pending_guardian != Pubkey::default(), VultrError::NoPendingChange);
+
+    let elapsed = clock.unix_timestamp - pool.guardian_change_timestamp;
+    require!(elapsed >= ADMIN_TIMELOCK_SECONDS, VultrError::TimelockNotExpired);
+    require!(elapsed <= PENDING_CHANGE_EXPIRY_SECONDS, VultrError::TimelockExpired);
+
+    // The admin may have changed since the proposal
+    require!(pool.pending_guardian != pool.admin, VultrError::GuardianCannotBeAdmin);
+
+    let old_guardian = pool.guardian;
+    pool.guardian = pool.pending_guardian;
+    pool.pending_guardian = Pubkey::default();
+    pool.guardian_change_timestamp = 0;
+
+    msg!("Guardian update FINALIZED!");
+    msg!("Old guardian: {}", old_guardian);
+    msg!("New guardian: {}", pool.guardian);
+
+    Ok(())
+}
+
+/// Cancel pending guardian update
+pub fn handler_cancel_guardian(ctx: Context<FinalizeGuardian>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(pool.pending_guardian != Pubkey::default(), VultrError::NoPendingChangeToCancel);
+
+    let cancelled_guardian = pool.pending_guardian;
+    pool.pending_guardian = Pubkey::default();
+    pool.guardian_change_timestamp = 0;
+
+    msg!("Guardian update CANCELLED. Was going to: {}", cancelled_guardian);
+
+    Ok(())
+}
+
+/// Accounts required for emergency_revoke_bot instruction
+#[derive(Accounts)]
+pub struct EmergencyRevokeBot<'info> {
+    /// The admin or guardian must sign
+    #[account(
+        constraint = authority.key() == pool.admin
+            || (pool.guardian != Pubkey::default() && authority.key() == pool.guardian)
+            @ VultrError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// The pool whose bot wallet is revoked
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Immediately revoke the bot wallet, disabling record_profit
+///
+/// Also cancels any pending bot wallet change, so a replacement proposed
+/// before the incident cannot be finalized behind the revocation.
+pub fn handler_emergency_revoke_bot(ctx: Context<EmergencyRevokeBot>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    if pool.pending_bot_wallet != Pubkey::default() {
+        msg!("Pending bot wallet CANCELLED: {}", pool.pending_bot_wallet);
+    }
+    pool.pending_bot_wallet = Pubkey::default();
+    pool.bot_wallet_change_timestamp = 0;
+
+    if pool.bot_wallet == Pubkey::default() {
+        msg!("Bot wallet is already revoked");
+        return Ok(());
+    }
+
+    let revoked_bot_wallet = pool.bot_wallet;
+    pool.bot_wallet = Pubkey::default();
+
+    msg!("Bot wallet REVOKED by {}", ctx.accounts.authority.key());
+    msg!("Revoked bot wallet: {}", revoked_bot_wallet);
+    msg!("record_profit is disabled until a new bot wallet is finalized");
+
+    Ok(())
+}
+
 // =============================================================================
 // SECURITY FIX-7: Fee Update with Timelock
 // =============================================================================
@@ -436,6 +682,253 @@ pub fn handler_cancel_fees(ctx: Context<FinalizeFees>) -> Result<()> {
     Ok(())
 }
 
+// =============================================================================
+// Deposit Fee Update with Timelock
+// =============================================================================
+// Same propose -> wait 24h -> finalize flow as the profit split, reusing its
+// account structs. A pending change is marked by a non-zero timestamp because
+// 0 bps (no fee) is a valid target.
+
+/// Propose a deposit fee update (24-hour timelock)
+pub fn handler_propose_deposit_fee(ctx: Context<ProposeFees>, deposit_fee_bps: u16) -> Result<()> {
+    require!(deposit_fee_bps <= MAX_DEPOSIT_FEE_BPS, VultrError::FeeExceedsMax);
+
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    pool.pending_deposit_fee_bps = deposit_fee_bps;
+    pool.deposit_fee_change_timestamp = clock.unix_timestamp;
+
+    msg!("Deposit fee update PROPOSED: {} -> {} bps", pool.deposit_fee_bps, deposit_fee_bps);
+    msg!("Timelock expires at: {} (in {} seconds)",
+        clock.unix_timestamp + ADMIN_TIMELOCK_SECONDS, ADMIN_TIMELOCK_SECONDS);
+
+    Ok(())
+}
+
+/// Finalize a deposit fee update after timelock expires
+pub fn handler_finalize_deposit_fee(ctx: Context<FinalizeFees>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(pool.deposit_fee_change_timestamp != 0, VultrError::NoPendingChange);
+
+    // Check timelock has expired
+    let elapsed = clock.unix_timestamp - pool.deposit_fee_change_timestamp;
+    require!(elapsed >= ADMIN_TIMELOCK_SECONDS, VultrError::TimelockNotExpired);
+
+    // Check change hasn't expired (7 days max)
+    require!(elapsed <= PENDING_CHANGE_EXPIRY_SECONDS, VultrError::TimelockExpired);
+
+    let old_fee = pool.deposit_fee_bps;
+    pool.deposit_fee_bps = pool.pending_deposit_fee_bps;
+    pool.pending_deposit_fee_bps = 0;
+    pool.deposit_fee_change_timestamp = 0;
+
+    msg!("Deposit fee update FINALIZED: {} -> {} bps", old_fee, pool.deposit_fee_bps);
+
+    Ok(())
+}
+
+/// Cancel pending deposit fee update
+pub fn handler_cancel_deposit_fee(ctx: Context<FinalizeFees>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(pool.deposit_fee_change_timestamp != 0, VultrError::NoPendingChangeToCancel);
+
+    pool.pending_deposit_fee_bps = 0;
+    pool.deposit_fee_change_timestamp = 0;
+
+    msg!("Deposit fee update CANCELLED");
+
+    Ok(())
+}
+
+// =============================================================================
+// Withdrawal Fee Update with Timelock
+// =============================================================================
+// Same flow as the deposit fee. The fee stays in the vault, so raising it is
+// a direct cost to every exiting depositor and must be announced 24h ahead.
+
+/// Propose a withdrawal fee update (24-hour timelock)
+pub fn handler_propose_withdrawal_fee(ctx: Context<ProposeFees>, withdrawal_fee_bps: u16) -> Result<()> {
+    require!(withdrawal_fee_bps <= MAX_WITHDRAWAL_FEE_BPS, VultrError::WithdrawalFeeExceedsMax);
+
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    pool.pending_withdrawal_fee_bps = withdrawal_fee_bps;
+    pool.withdrawal_fee_change_timestamp = clock.unix_timestamp;
+
+    msg!("Withdrawal fee update PROPOSED: {} -> {} bps", pool.withdrawal_fee_bps, withdrawal_fee_bps);
+    msg!("Timelock expires at: {} (in {} seconds)",
+        clock.unix_timestamp + ADMIN_TIMELOCK_SECONDS, ADMIN_TIMELOCK_SECONDS);
+
+    Ok(())
+}
+
+/// Finalize a withdrawal fee update after timelock expires
+pub fn handler_finalize_withdrawal_fee(ctx: Context<FinalizeFees>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(pool.withdrawal_fee_change_timestamp != 0, VultrError::NoPendingChange);
+
+    // Check timelock has expired
+    let elapsed = clock.unix_timestamp - pool.withdrawal_fee_change_timestamp;
+    require!(elapsed >= ADMIN_TIMELOCK_SECONDS, VultrError::TimelockNotExpired);
+
+    // Check change hasn't expired (7 days max)
+    require!(elapsed <= PENDING_CHANGE_EXPIRY_SECONDS, VultrError::TimelockExpired);
+
+    let old_fee = pool.withdrawal_fee_bps;
+    pool.withdrawal_fee_bps = pool.pending_withdrawal_fee_bps;
+    pool.pending_withdrawal_fee_bps = 0;
+    pool.withdrawal_fee_change_timestamp = 0;
+
+    msg!("Withdrawal fee update FINALIZED: {} -> {} bps", old_fee, pool.withdrawal_fee_bps);
+
+    Ok(())
+}
+
+/// Cancel pending withdrawal fee update
+pub fn handler_cancel_withdrawal_fee(ctx: Context<FinalizeFees>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(pool.withdrawal_fee_change_timestamp != 0, VultrError::NoPendingChangeToCancel);
+
+    pool.pending_withdrawal_fee_bps = 0;
+    pool.withdrawal_fee_change_timestamp = 0;
+
+    msg!("Withdrawal fee update CANCELLED");
+
+    Ok(())
+}
+
+// =============================================================================
+// Share Price Sanity Band
+// =============================================================================
+// Defense-in-depth bound on total_deposits / total_shares. No timelock: the
+// band can only make deposits and withdrawals revert, never move funds, and
+// the admin can already pause the pool outright.
+
+/// Accounts required for set_share_price_band instruction
+#[derive(Accounts)]
+pub struct SetSharePriceBand<'info> {
+    /// The admin must sign
+    #[account(
+        constraint = admin.key() == pool.admin @ VultrError::AdminOnly
+    )]
+    pub admin: Signer<'info>,
+
+    /// The pool to update
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Set the share price sanity band (0 disables a bound)
+pub fn handler_set_share_price_band(
+    ctx: Context<SetSharePriceBand>,
+    min_share_price: u64,
+    max_share_price: u64,
+) -> Result<()> {
+    require!(
+        max_share_price == 0 || min_share_price <= max_share_price,
+        VultrError::InvalidSharePriceBand
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    pool.min_share_price = min_share_price;
+    pool.max_share_price = max_share_price;
+
+    msg!("Share price band set: min {} max {} (current {})",
+        min_share_price, max_share_price, pool.share_price()?);
+
+    Ok(())
+}
+
+// =============================================================================
+// Per-User Deposit Cap
+// =============================================================================
+// Keeps a single whale from crowding retail out of a small launch pool. No
+// timelock: it only limits new deposits and never touches existing funds.
+
+/// Accounts required for set_max_deposit_per_user instruction
+#[derive(Accounts)]
+pub struct SetMaxDepositPerUser<'info> {
+    /// The admin must sign
+    #[account(
+        constraint = admin.key() == pool.admin @ VultrError::AdminOnly
+    )]
+    pub admin: Signer<'info>,
+
+    /// The pool to update
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Set the per-user cumulative deposit cap (0 = unlimited)
+pub fn handler_set_max_deposit_per_user(
+    ctx: Context<SetMaxDepositPerUser>,
+    max_deposit_per_user: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let old_cap = pool.max_deposit_per_user;
+
+    pool.max_deposit_per_user = max_deposit_per_user;
+
+    msg!("Per-user deposit cap updated: {} -> {}", old_cap, max_deposit_per_user);
+
+    Ok(())
+}
+
+// =============================================================================
+// Minimum Profit
+// =============================================================================
+// Lets the team ignore dust liquidations that are not worth the compute and
+// MEV risk. No timelock: it can only make record_profit revert.
+
+/// Accounts required for set_min_profit_amount instruction
+#[derive(Accounts)]
+pub struct SetMinProfitAmount<'info> {
+    /// The admin must sign
+    #[account(
+        constraint = admin.key() == pool.admin @ VultrError::AdminOnly
+    )]
+    pub admin: Signer<'info>,
+
+    /// The pool to update
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Set the smallest profit record_profit accepts (0 = any non-zero profit)
+pub fn handler_set_min_profit_amount(
+    ctx: Context<SetMinProfitAmount>,
+    min_profit_amount: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let old_min = pool.min_profit_amount;
+
+    pool.min_profit_amount = min_profit_amount;
+
+    msg!("Minimum profit updated: {} -> {}", old_min, min_profit_amount);
+
+    Ok(())
+}
+
 // =============================================================================
 // Legacy handlers (kept for backwards compatibility during migration)
 // These will be removed in a future version
@@ -521,46 +1014,12 @@ pub fn handler_update_bot_wallet(ctx: Context<UpdateBotWallet>) -> Result<()> {
 
     require!(new_bot_wallet != Pubkey::default(), VultrError::InvalidAddress);
 
+    // A revoked bot may only be replaced through the timelocked path
+    require!(pool.bot_wallet != Pubkey::default(), VultrError::BotWalletRevoked);
+
     pool.bot_wallet = new_bot_wallet;
 
     msg!("WARNING: Using deprecated instant bot wallet update. Use propose_bot_wallet/finalize_bot_wallet instead.");
 
     Ok(())
 }
-
-/// Accounts required for transfer_admin instruction (DEPRECATED)
-#[derive(Accounts)]
-pub struct TransferAdmin<'info> {
-    /// The current admin must sign
-    #[account(
-        constraint = admin.key() == pool.admin @ VultrError::AdminOnly
-    )]
-    pub admin: Signer<'info>,
-
-    /// The pool to transfer admin for
-    #[account(
-        mut,
-        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
-        bump = pool.bump
-    )]
-    pub pool: Account<'info, Pool>,
-
-    /// The new admin
-    /// CHECK: This is just the new admin address, we just store it
-    pub new_admin: UncheckedAccount<'info>,
-}
-
-/// DEPRECATED: Use propose_admin_transfer + finalize_admin_transfer instead
-pub fn handler_transfer_admin(ctx: Context<TransferAdmin>) -> Result<()> {
-    let pool = &mut ctx.accounts.pool;
-    let new_admin = ctx.accounts.new_admin.key();
-
-    require!(new_admin != Pubkey::default(), VultrError::InvalidAddress);
-    require!(new_admin != pool.admin, VultrError::InvalidAuthority);
-
-    pool.admin = new_admin;
-
-    msg!("WARNING: Using deprecated instant admin transfer. Use propose_admin_transfer/finalize_admin_transfer instead.");
-
-    Ok(())
-}
@@ -0,0 +1,75 @@
+// =============================================================================
+// Close Depositor Instruction
+// =============================================================================
+// Lets a depositor who has fully exited close their Depositor PDA and reclaim
+// its rent.
+//
+// The account only holds lifetime stats and withdrawal history; no funds are
+// tied to it. A later deposit simply re-creates it (deposit uses
+// init_if_needed), starting from fresh stats.
+//
+// Only the owner can close, and only while their share ATA is empty. As with
+// reset_depositor_stats, the zero-balance check covers that one account and
+// closing clears total_deposited, so max_deposit_per_user is only as strong
+// as this check.
+// =============================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::constants::*;
+use crate::error::VultrError;
+use crate::state::{Depositor, Pool};
+
+/// Accounts required for the close_depositor instruction
+#[derive(Accounts)]
+pub struct CloseDepositor<'info> {
+    /// The depositor closing their own account; receives the rent
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The pool the depositor belongs to
+    #[account(
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// The owner's depositor state account, closed to the owner
+    #[account(
+        mut,
+        close = owner,
+        seeds = [DEPOSITOR_SEED, pool.key().as_ref(), owner.key().as_ref()],
+        bump = depositor_account.bump,
+        constraint = depositor_account.owner == owner.key() @ VultrError::Unauthorized
+    )]
+    pub depositor_account: Account<'info, Depositor>,
+
+    /// The owner's share ATA - must be empty
+    #[account(
+        constraint = owner_share_account.key() == get_associated_token_address_with_program_id(
+            &owner.key(),
+            &pool.share_mint,
+            owner_share_account.to_account_info().owner,
+        ) @ VultrError::ShareAccountNotAssociated,
+        constraint = owner_share_account.mint == pool.share_mint @ VultrError::InvalidShareMint,
+        constraint = owner_share_account.owner == owner.key() @ VultrError::InvalidTokenAccountOwner,
+        constraint = owner_share_account.amount == 0 @ VultrError::SharesOutstanding
+    )]
+    pub owner_share_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+/// Handler for close_depositor
+pub fn handler_close_depositor(ctx: Context<CloseDepositor>) -> Result<()> {
+    let depositor_account = &ctx.accounts.depositor_account;
+
+    msg!(
+        "Closing depositor account: {} deposited, {} withdrawn over {} deposits",
+        depositor_account.total_deposited,
+        depositor_account.total_withdrawn,
+        depositor_account.deposit_count
+    );
+
+    Ok(())
+}
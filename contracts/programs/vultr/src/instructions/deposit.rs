@@ -5,21 +5,30 @@
 //
 // Flow:
 // 1. User specifies amount of deposit tokens to deposit
-// 2. Program calculates shares to mint based on current pool value
-// 3. Deposit tokens are transferred from user to vault
-// 4. Share tokens are minted to user's share account
-// 5. Depositor account is updated with statistics
+// 2. Deposit fee (if configured) is taken from the gross amount
+// 3. Program calculates shares to mint on the net amount
+// 4. Net tokens go to the vault, the fee goes to the treasury
+// 5. Share tokens are minted to user's share account
+// 6. Depositor account is updated with statistics
 //
 // Share calculation:
 // - First deposit: shares = deposit amount (1:1)
 // - Later deposits: shares = (deposit * total_shares) / total_pool_value
+//
+// Transfer fees (Token-2022):
+// Shares and total_deposits are based on the vault's balance delta across the
+// transfer, not the requested amount, so a fee-on-transfer mint can never
+// credit the depositor with tokens the vault did not receive.
 // =============================================================================
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{
+    self, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+};
 
 use crate::constants::*;
 use crate::error::VultrError;
+use crate::events::DepositEvent;
 use crate::state::{Depositor, Pool};
 
 /// Accounts required for the deposit instruction
@@ -43,12 +52,13 @@ pub struct DepositToPool<'info> {
     /// Constraints:
     /// - seeds: Validates this is the correct pool PDA
     /// - bump: Validates using stored bump
-    /// - constraint: Pool must not be paused
+    /// - constraint: Deposits must not be paused
     #[account(
         mut,
         seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
         bump = pool.bump,
-        constraint = !pool.is_paused @ VultrError::PoolPaused
+        has_one = treasury @ VultrError::InvalidPDA,
+        constraint = !pool.deposits_paused() @ VultrError::PoolPaused
     )]
     pub pool: Account<'info, Pool>,
 
@@ -70,8 +80,11 @@ pub struct DepositToPool<'info> {
     // =========================================================================
 
     /// The deposit token mint (e.g., USDC)
-    /// Used to validate token accounts
-    pub deposit_mint: Account<'info, Mint>,
+    /// Used to validate token accounts and for transfer_checked decimals
+    #[account(
+        constraint = deposit_mint.key() == pool.deposit_mint @ VultrError::InvalidDepositMint
+    )]
+    pub deposit_mint: InterfaceAccount<'info, Mint>,
 
     /// The share token mint (VLTR)
     /// Program will mint new shares to the user
@@ -80,7 +93,7 @@ pub struct DepositToPool<'info> {
         seeds = [SHARE_MINT_SEED, pool.key().as_ref()],
         bump = pool.share_mint_bump
     )]
-    pub share_mint: Account<'info, Mint>,
+    pub share_mint: InterfaceAccount<'info, Mint>,
 
     // =========================================================================
     // Token Accounts
@@ -92,7 +105,7 @@ pub struct DepositToPool<'info> {
         constraint = user_deposit_account.mint == deposit_mint.key() @ VultrError::InvalidDepositMint,
         constraint = user_deposit_account.owner == depositor.key() @ VultrError::InvalidTokenAccountOwner
     )]
-    pub user_deposit_account: Account<'info, TokenAccount>,
+    pub user_deposit_account: InterfaceAccount<'info, TokenAccount>,
 
     /// User's share token account (destination for minted shares)
     #[account(
@@ -100,7 +113,7 @@ pub struct DepositToPool<'info> {
         constraint = user_share_account.mint == share_mint.key() @ VultrError::InvalidShareMint,
         constraint = user_share_account.owner == depositor.key() @ VultrError::InvalidTokenAccountOwner
     )]
-    pub user_share_account: Account<'info, TokenAccount>,
+    pub user_share_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Pool's vault (destination for deposited tokens)
     #[account(
@@ -108,14 +121,18 @@ pub struct DepositToPool<'info> {
         seeds = [VAULT_SEED, pool.key().as_ref()],
         bump = pool.vault_bump
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Protocol treasury (receives the deposit fee)
+    #[account(mut)]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
 
     // =========================================================================
     // Programs
     // =========================================================================
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Handler for the deposit instruction
@@ -129,15 +146,6 @@ pub fn handler_deposit(ctx: Context<DepositToPool>, amount: u64, min_shares_out:
     // Input Validation
     // =========================================================================
 
-    // Check amount is greater than 0
-    require!(amount > 0, VultrError::InvalidAmount);
-
-    // Check minimum deposit
-    require!(amount >= MIN_DEPOSIT_AMOUNT, VultrError::BelowMinimumDeposit);
-
-    // Check maximum single deposit
-    require!(amount <= MAX_DEPOSIT_AMOUNT, VultrError::ExceedsMaxDeposit);
-
     // Check user has sufficient balance
     require!(
         ctx.accounts.user_deposit_account.amount >= amount,
@@ -145,73 +153,106 @@ pub fn handler_deposit(ctx: Context<DepositToPool>, amount: u64, min_shares_out:
     );
 
     // =========================================================================
-    // First Deposit Protection (Share Price Inflation Attack Prevention)
+    // Calculate Fee and Shares to Mint
     // =========================================================================
+    // Amount limits, first-deposit minimum, sanity band, MIN_SHARES_MINTED,
+    // pool cap and share supply are all checked by Pool::quote_deposit, which
+    // the quote_deposit view also uses
 
     let pool = &ctx.accounts.pool;
+    let (deposit_fee, net_amount, quoted_shares) = pool.quote_deposit(amount)?;
 
-    // If this is the first deposit (pool is empty), require larger minimum
-    // This prevents the share price inflation attack where:
-    // 1. Attacker deposits 1 token, gets 1 share
-    // 2. Attacker transfers many tokens directly to vault (not through deposit)
-    // 3. Share price becomes inflated, next depositor gets ~0 shares
     if pool.total_shares == 0 {
-        require!(
-            amount >= MIN_FIRST_DEPOSIT,
-            VultrError::BelowMinimumDeposit
-        );
         msg!("First deposit - requiring minimum of {} tokens", MIN_FIRST_DEPOSIT);
     }
 
-    // =========================================================================
-    // Calculate Shares to Mint
-    // =========================================================================
-
-    let shares_to_mint = pool.calculate_shares_to_mint(amount)?;
-
-    // Ensure we're minting at least MIN_SHARES_MINTED (prevent rounding attacks)
-    // This protects against attacks where share price is manipulated such that
-    // deposit_amount / share_price rounds down to 0 or very small number
-    require!(
-        shares_to_mint >= MIN_SHARES_MINTED,
-        VultrError::ShareAmountZero
-    );
-
-    // Slippage protection: ensure user receives at least min_shares_out
-    // This protects against share price changes between tx submission and execution
-    if min_shares_out > 0 {
+    // Per-user cap on cumulative deposits (0 = unlimited)
+    if pool.max_deposit_per_user > 0 {
+        let user_total = ctx
+            .accounts
+            .depositor_account
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(VultrError::MathOverflow)?;
         require!(
-            shares_to_mint >= min_shares_out,
-            VultrError::SlippageExceeded
+            user_total <= pool.max_deposit_per_user,
+            VultrError::ExceedsUserDepositCap
         );
     }
 
-    // Check pool size limit
-    let new_total = pool
-        .total_deposits
-        .checked_add(amount)
-        .ok_or(VultrError::MathOverflow)?;
-    require!(new_total <= pool.max_pool_size, VultrError::ExceedsMaxPoolSize);
-
-    msg!("Depositing {} tokens for {} shares", amount, shares_to_mint);
+    msg!(
+        "Depositing {} tokens ({} fee) for {} quoted shares",
+        amount,
+        deposit_fee,
+        quoted_shares
+    );
 
     // =========================================================================
     // Transfer Deposit Tokens: User -> Vault
     // =========================================================================
 
+    let decimals = ctx.accounts.deposit_mint.decimals;
+    let vault_before = ctx.accounts.vault.amount;
+
     // Create the transfer instruction
     // This transfers tokens from the user's account to the vault
     let transfer_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.user_deposit_account.to_account_info(),
+            mint: ctx.accounts.deposit_mint.to_account_info(),
             to: ctx.accounts.vault.to_account_info(),
             authority: ctx.accounts.depositor.to_account_info(),
         },
     );
 
     // Execute the transfer
-    token::transfer(transfer_ctx, amount)?;
+    token_interface::transfer_checked(transfer_ctx, net_amount, decimals)?;
+
+    // Deposit fee: User -> Treasury
+    if deposit_fee > 0 {
+        let fee_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.user_deposit_account.to_account_info(),
+                mint: ctx.accounts.deposit_mint.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        );
+        token_interface::transfer_checked(fee_ctx, deposit_fee, decimals)?;
+    }
+
+    // =========================================================================
+    // Credit What the Vault Actually Received
+    // =========================================================================
+    // A Token-2022 transfer fee is withheld from the destination, so the vault
+    // can hold less than net_amount. Price shares on the real delta; for
+    // fee-less mints this is exactly net_amount and the quote stands
+
+    ctx.accounts.vault.reload()?;
+    let received = ctx
+        .accounts
+        .vault
+        .amount
+        .checked_sub(vault_before)
+        .ok_or(VultrError::MathUnderflow)?;
+
+    let shares_to_mint = if received == net_amount {
+        quoted_shares
+    } else {
+        ctx.accounts.pool.quote_received_deposit(received)?
+    };
+
+    // Slippage protection: ensure user receives at least min_shares_out
+    // This protects against share price changes between tx submission and
+    // execution, and against transfer fees eating into the deposit
+    if min_shares_out > 0 {
+        require!(
+            shares_to_mint >= min_shares_out,
+            VultrError::SlippageExceeded
+        );
+    }
 
     // =========================================================================
     // Mint Share Tokens to User
@@ -238,7 +279,7 @@ pub fn handler_deposit(ctx: Context<DepositToPool>, amount: u64, min_shares_out:
     );
 
     // Execute the mint
-    token::mint_to(mint_ctx, shares_to_mint)?;
+    token_interface::mint_to(mint_ctx, shares_to_mint)?;
 
     // =========================================================================
     // Update Depositor Account (do this first to avoid borrow issues)
@@ -272,7 +313,7 @@ pub fn handler_deposit(ctx: Context<DepositToPool>, amount: u64, min_shares_out:
 
     pool.total_deposits = pool
         .total_deposits
-        .checked_add(amount)
+        .checked_add(received)
         .ok_or(VultrError::MathOverflow)?;
 
     pool.total_shares = pool
@@ -285,10 +326,20 @@ pub fn handler_deposit(ctx: Context<DepositToPool>, amount: u64, min_shares_out:
     // =========================================================================
 
     msg!("Deposit successful!");
-    msg!("Amount deposited: {}", amount);
+    msg!("Amount deposited: {} (fee: {})", received, deposit_fee);
     msg!("Shares minted: {}", shares_to_mint);
     msg!("New pool total deposits: {}", pool.total_deposits);
     msg!("New pool total shares: {}", pool.total_shares);
 
+    emit!(DepositEvent {
+        pool: pool_key,
+        depositor: depositor_key,
+        amount,
+        deposit_fee,
+        shares_minted: shares_to_mint,
+        share_price: pool.share_price()?,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
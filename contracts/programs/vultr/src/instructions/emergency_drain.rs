@@ -0,0 +1,145 @@
+// =============================================================================
+// Emergency Drain Instruction
+// =============================================================================
+// Last-resort evacuation of the entire vault to a recovery account.
+//
+// Intended for a worst-case exploit where the team must move funds to a safe
+// multisig and make depositors whole off-chain.
+//
+// Authorization is deliberately strong:
+// - Admin AND guardian must both sign (distinct keys; guardian changes are
+//   timelocked)
+// - Pool must be paused with pause_pool for at least
+//   EMERGENCY_DRAIN_TIMELOCK_SECONDS (14 days), so depositors get a full
+//   week of emergency_withdraw first
+//
+// The drain is terminal: the pool is marked disabled, stays paused forever,
+// and deposits, withdrawals, emergency withdrawals and profit recording are
+// no longer possible.
+// =============================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::constants::*;
+use crate::error::VultrError;
+use crate::state::Pool;
+
+/// Accounts required for the emergency_drain instruction
+#[derive(Accounts)]
+#[instruction(recovery: Pubkey)]
+pub struct EmergencyDrain<'info> {
+    /// The admin must sign
+    #[account(
+        constraint = admin.key() == pool.admin @ VultrError::AdminOnly
+    )]
+    pub admin: Signer<'info>,
+
+    /// The guardian must also sign (a guardian must be configured)
+    #[account(
+        constraint = pool.guardian != Pubkey::default() @ VultrError::Unauthorized,
+        constraint = guardian.key() == pool.guardian @ VultrError::Unauthorized
+    )]
+    pub guardian: Signer<'info>,
+
+    /// The pool to drain
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump,
+        constraint = !pool.is_disabled @ VultrError::PoolDisabled
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// The deposit token mint (needed for transfer_checked)
+    #[account(
+        constraint = deposit_mint.key() == pool.deposit_mint @ VultrError::InvalidDepositMint
+    )]
+    pub deposit_mint: InterfaceAccount<'info, Mint>,
+
+    /// Pool's vault (drained in full)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Destination token account, owned by the `recovery` address
+    #[account(
+        mut,
+        constraint = recovery_account.mint == pool.deposit_mint @ VultrError::InvalidDepositMint,
+        constraint = recovery_account.owner == recovery @ VultrError::InvalidTokenAccountOwner
+    )]
+    pub recovery_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Handler for emergency_drain
+///
+/// # Arguments
+/// * `recovery` - Owner of the recovery token account (e.g., a safe multisig)
+pub fn handler_emergency_drain(ctx: Context<EmergencyDrain>, recovery: Pubkey) -> Result<()> {
+    require!(recovery != Pubkey::default(), VultrError::InvalidAddress);
+
+    let pool = &ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    // Opens a week after emergency_withdraw so depositors can exit first.
+    // Timed from the full pause: an earlier PAUSE_WITHDRAWALS flag does not
+    // count towards it
+    require!(pool.is_paused, VultrError::PoolNotPaused);
+
+    let paused_duration = clock.unix_timestamp - pool.full_pause_timestamp;
+    require!(
+        paused_duration >= EMERGENCY_DRAIN_TIMELOCK_SECONDS,
+        VultrError::EmergencyTimelockNotExpired
+    );
+
+    // =========================================================================
+    // Transfer entire vault: Vault -> Recovery
+    // =========================================================================
+
+    let drain_amount = ctx.accounts.vault.amount;
+
+    if drain_amount > 0 {
+        let deposit_mint_key = pool.deposit_mint;
+        let pool_seeds = &[
+            POOL_SEED,
+            deposit_mint_key.as_ref(),
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.deposit_mint.to_account_info(),
+                to: ctx.accounts.recovery_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(
+            transfer_ctx,
+            drain_amount,
+            ctx.accounts.deposit_mint.decimals,
+        )?;
+    }
+
+    // =========================================================================
+    // Disable the pool permanently
+    // =========================================================================
+
+    let pool = &mut ctx.accounts.pool;
+    pool.total_deposits = 0;
+    pool.is_disabled = true;
+
+    msg!("EMERGENCY DRAIN: {} tokens moved to {}", drain_amount, recovery);
+    msg!("Pool {} is permanently disabled", pool.key());
+    msg!("Outstanding shares at drain: {}", pool.total_shares);
+
+    Ok(())
+}
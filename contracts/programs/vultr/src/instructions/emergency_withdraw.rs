@@ -0,0 +1,230 @@
+// =============================================================================
+// Emergency Withdraw Instruction (SECURITY FIX-6)
+// =============================================================================
+// Escape hatch for depositors if withdrawals have been paused for > 7 days,
+// either by pause_pool or by the PAUSE_WITHDRAWALS flag.
+// This prevents admins from indefinitely locking user funds.
+//
+// Unlike withdraw, this bypasses the withdrawals_paused() check. The amount is
+// still computed with calculate_withdrawal_amount and pool totals are updated
+// exactly as a normal withdrawal would.
+// =============================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::constants::*;
+use crate::error::VultrError;
+use crate::events::EmergencyWithdrawEvent;
+use crate::state::{Depositor, Pool};
+
+/// Accounts required for emergency_withdraw instruction
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    // =========================================================================
+    // Signers
+    // =========================================================================
+
+    /// The user withdrawing tokens
+    /// Must sign to authorize share burning
+    #[account(mut)]
+    pub withdrawer: Signer<'info>,
+
+    // =========================================================================
+    // Pool Accounts
+    // =========================================================================
+
+    /// The pool to withdraw from (withdrawals paused for > 7 days)
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump,
+        // Note: We check withdrawals_paused() and pause_timestamp in the handler
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// The withdrawer's depositor state account
+    #[account(
+        mut,
+        seeds = [DEPOSITOR_SEED, pool.key().as_ref(), withdrawer.key().as_ref()],
+        bump = depositor_account.bump,
+        constraint = depositor_account.owner == withdrawer.key() @ VultrError::Unauthorized
+    )]
+    pub depositor_account: Account<'info, Depositor>,
+
+    // =========================================================================
+    // Token Mints
+    // =========================================================================
+
+    /// The deposit token mint (e.g., USDC)
+    #[account(
+        constraint = deposit_mint.key() == pool.deposit_mint @ VultrError::InvalidDepositMint
+    )]
+    pub deposit_mint: InterfaceAccount<'info, Mint>,
+
+    /// The share token mint (VLTR)
+    /// Program will burn shares from user
+    #[account(
+        mut,
+        seeds = [SHARE_MINT_SEED, pool.key().as_ref()],
+        bump = pool.share_mint_bump
+    )]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+
+    // =========================================================================
+    // Token Accounts
+    // =========================================================================
+
+    /// User's deposit token account (destination for withdrawn tokens)
+    #[account(
+        mut,
+        constraint = user_deposit_account.mint == deposit_mint.key() @ VultrError::InvalidDepositMint,
+        constraint = user_deposit_account.owner == withdrawer.key() @ VultrError::InvalidTokenAccountOwner
+    )]
+    pub user_deposit_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// User's share token account (source of shares to burn)
+    #[account(
+        mut,
+        constraint = user_share_account.mint == share_mint.key() @ VultrError::InvalidShareMint,
+        constraint = user_share_account.owner == withdrawer.key() @ VultrError::InvalidTokenAccountOwner
+    )]
+    pub user_share_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Pool's vault (source of withdrawal tokens)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    // =========================================================================
+    // Programs
+    // =========================================================================
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Handler for emergency_withdraw instruction
+///
+/// Allows withdrawal when withdrawals have been paused for > 7 days
+///
+/// # Arguments
+/// * `ctx` - The instruction context with all accounts
+/// * `shares_to_burn` - Number of share tokens to burn
+pub fn handler_emergency_withdraw(ctx: Context<EmergencyWithdraw>, shares_to_burn: u64) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    // =========================================================================
+    // SECURITY FIX-6: Validate emergency withdrawal conditions
+    // =========================================================================
+
+    // Vault was evacuated by emergency_drain - nothing left to withdraw
+    require!(!pool.is_disabled, VultrError::PoolDisabled);
+
+    // Withdrawals must be paused, by pause_pool or PAUSE_WITHDRAWALS
+    require!(pool.withdrawals_paused(), VultrError::PoolNotPaused);
+
+    // Withdrawals must have been paused for at least 7 days
+    let paused_duration = clock.unix_timestamp - pool.pause_timestamp;
+    require!(
+        paused_duration >= EMERGENCY_TIMELOCK_SECONDS,
+        VultrError::EmergencyTimelockNotExpired
+    );
+
+    msg!("Emergency withdrawal triggered - pool paused for {} seconds (minimum: {})",
+        paused_duration, EMERGENCY_TIMELOCK_SECONDS);
+
+    // =========================================================================
+    // Standard withdrawal logic (same as regular withdraw)
+    // =========================================================================
+
+    require!(shares_to_burn > 0, VultrError::InvalidAmount);
+    require!(
+        ctx.accounts.user_share_account.amount >= shares_to_burn,
+        VultrError::InsufficientShares
+    );
+    require!(
+        ctx.accounts.pool.total_shares >= shares_to_burn,
+        VultrError::InsufficientShares
+    );
+
+    let withdrawal_amount = pool.calculate_withdrawal_amount(shares_to_burn)?;
+
+    require!(
+        ctx.accounts.vault.amount >= withdrawal_amount,
+        VultrError::InsufficientBalance
+    );
+
+    // Burn shares
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.share_mint.to_account_info(),
+            from: ctx.accounts.user_share_account.to_account_info(),
+            authority: ctx.accounts.withdrawer.to_account_info(),
+        },
+    );
+    token_interface::burn(burn_ctx, shares_to_burn)?;
+
+    // Transfer tokens from vault to user
+    let deposit_mint_key = ctx.accounts.deposit_mint.key();
+    let pool_seeds = &[
+        POOL_SEED,
+        deposit_mint_key.as_ref(),
+        &[ctx.accounts.pool.bump],
+    ];
+    let signer_seeds = &[&pool_seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.vault.to_account_info(),
+            mint: ctx.accounts.deposit_mint.to_account_info(),
+            to: ctx.accounts.user_deposit_account.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(
+        transfer_ctx,
+        withdrawal_amount,
+        ctx.accounts.deposit_mint.decimals,
+    )?;
+
+    // Update pool state
+    let pool = &mut ctx.accounts.pool;
+    pool.total_deposits = pool
+        .total_deposits
+        .checked_sub(withdrawal_amount)
+        .ok_or(VultrError::MathUnderflow)?;
+    pool.total_shares = pool
+        .total_shares
+        .checked_sub(shares_to_burn)
+        .ok_or(VultrError::MathUnderflow)?;
+
+    pool.emergency_withdrawn_total = pool
+        .emergency_withdrawn_total
+        .checked_add(withdrawal_amount)
+        .ok_or(VultrError::MathOverflow)?;
+
+    // Update depositor account
+    let depositor_account = &mut ctx.accounts.depositor_account;
+    depositor_account.record_withdrawal(withdrawal_amount, clock.unix_timestamp)?;
+
+    emit!(EmergencyWithdrawEvent {
+        pool: pool.key(),
+        user: ctx.accounts.withdrawer.key(),
+        shares_burned: shares_to_burn,
+        amount: withdrawal_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("EMERGENCY WITHDRAWAL successful!");
+    msg!("Shares burned: {}", shares_to_burn);
+    msg!("Amount withdrawn: {}", withdrawal_amount);
+
+    Ok(())
+}
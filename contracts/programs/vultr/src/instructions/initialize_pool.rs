@@ -8,13 +8,24 @@
 // - Added staking_rewards_vault (external account for VLTR staker rewards)
 // - Treasury is now an external account (not a PDA)
 // - Removed operator-related configuration
+//
+// TOKEN-2022:
+// The deposit mint may belong to either the legacy Token program or Token-2022.
+// Extensions are checked once here (see validate_deposit_mint_extensions); the
+// share mint and vault are created under the same token program as the
+// deposit mint.
 // =============================================================================
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_hook::TransferHook, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as SplMint;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::*;
-use crate::state::Pool;
+use crate::error::VultrError;
+use crate::state::{Pool, PoolConfig};
 
 /// Accounts required for the initialize_pool instruction.
 #[derive(Accounts)]
@@ -47,7 +58,9 @@ pub struct InitializePool<'info> {
     // =========================================================================
 
     /// The token users will deposit (e.g., USDC)
-    pub deposit_mint: Account<'info, Mint>,
+    /// May be a legacy Token or Token-2022 mint
+    #[account(mint::token_program = token_program)]
+    pub deposit_mint: InterfaceAccount<'info, Mint>,
 
     /// The share token mint (sVLTR) - created by this instruction
     #[account(
@@ -55,10 +68,11 @@ pub struct InitializePool<'info> {
         payer = admin,
         mint::decimals = SHARE_DECIMALS,
         mint::authority = pool,
+        mint::token_program = token_program,
         seeds = [SHARE_MINT_SEED, pool.key().as_ref()],
         bump
     )]
-    pub share_mint: Account<'info, Mint>,
+    pub share_mint: InterfaceAccount<'info, Mint>,
 
     // =========================================================================
     // Token Accounts
@@ -70,28 +84,29 @@ pub struct InitializePool<'info> {
         payer = admin,
         token::mint = deposit_mint,
         token::authority = pool,
+        token::token_program = token_program,
         seeds = [VAULT_SEED, pool.key().as_ref()],
         bump
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
 
     /// The treasury account for protocol fees (5%)
     /// This is an EXTERNAL token account, not a PDA
     /// Admin should create this beforehand and must own it
     #[account(
-        constraint = treasury.mint == deposit_mint.key() @ crate::error::VultrError::InvalidDepositMint,
-        constraint = treasury.owner == admin.key() @ crate::error::VultrError::InvalidTokenAccountOwner,
+        constraint = treasury.mint == deposit_mint.key() @ VultrError::InvalidDepositMint,
+        constraint = treasury.owner == admin.key() @ VultrError::InvalidTokenAccountOwner,
     )]
-    pub treasury: Account<'info, TokenAccount>,
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
 
     /// The staking rewards vault for VLTR stakers (15%)
     /// This is an EXTERNAL token account, not a PDA
     /// Admin should create this beforehand and must own it
     #[account(
-        constraint = staking_rewards_vault.mint == deposit_mint.key() @ crate::error::VultrError::InvalidDepositMint,
-        constraint = staking_rewards_vault.owner == admin.key() @ crate::error::VultrError::InvalidTokenAccountOwner,
+        constraint = staking_rewards_vault.mint == deposit_mint.key() @ VultrError::InvalidDepositMint,
+        constraint = staking_rewards_vault.owner == admin.key() @ VultrError::InvalidTokenAccountOwner,
     )]
-    pub staking_rewards_vault: Account<'info, TokenAccount>,
+    pub staking_rewards_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// The bot wallet address that will be authorized to call record_profit
     /// This is just checked as a valid pubkey
@@ -105,12 +120,74 @@ pub struct InitializePool<'info> {
     /// The System Program - required for creating accounts
     pub system_program: Program<'info, System>,
 
-    /// The Token Program - required for creating token accounts and mints
-    pub token_program: Program<'info, Token>,
+    /// The Token Program (legacy Token or Token-2022) that owns deposit_mint
+    /// Required for creating token accounts and mints
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Reject deposit mints whose Token-2022 extensions the pool cannot honour
+///
+/// Legacy Token mints have no extensions and always pass. Of the Token-2022
+/// extensions, two are refused:
+/// - TransferHook with a hook program or a hook authority set: every vault
+///   transfer would CPI into third-party code that can block withdrawals or
+///   need extra accounts, and an authority can install such a program after
+///   the pool exists. Only a fully inert hook (neither set) is accepted.
+/// - NonTransferable: deposits could never leave the vault again
+///
+/// Everything else is allowed. Transfer fees are handled by crediting the
+/// balance the vault actually received. Issuer controls such as a permanent
+/// delegate or a freezable default account state are already trusted by
+/// anyone holding the token (PYUSD ships with both), so the pool inherits
+/// that trust rather than refusing the mint.
+pub fn validate_deposit_mint_extensions(deposit_mint: &AccountInfo) -> Result<()> {
+    if *deposit_mint.owner != anchor_spl::token_2022::ID {
+        return Ok(());
+    }
+
+    let data = deposit_mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<SplMint>::unpack(&data)?;
+
+    for extension in mint.get_extension_types()? {
+        match extension {
+            ExtensionType::TransferHook => {
+                let hook = mint.get_extension::<TransferHook>()?;
+                require!(
+                    Option::<Pubkey>::from(hook.program_id).is_none()
+                        && Option::<Pubkey>::from(hook.authority).is_none(),
+                    VultrError::UnsupportedMintExtension
+                );
+            }
+            ExtensionType::NonTransferable => {
+                return Err(error!(VultrError::UnsupportedMintExtension));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
 }
 
 /// Handler function for initialize_pool
+///
+/// Creates the pool with the default configuration (80/15/5 split, no deposit
+/// fee, DEFAULT_POOL_SIZE cap, no guardian, no sanity band).
 pub fn handler_initialize_pool(ctx: Context<InitializePool>) -> Result<()> {
+    handler_initialize_pool_full(ctx, PoolConfig::default())
+}
+
+/// Handler function for initialize_pool_full
+///
+/// Same as initialize_pool but with every configurable field supplied up
+/// front, so a pool can launch fully configured in one transaction.
+pub fn handler_initialize_pool_full(ctx: Context<InitializePool>, config: PoolConfig) -> Result<()> {
+    config.validate()?;
+    require!(
+        config.guardian != ctx.accounts.admin.key(),
+        VultrError::GuardianCannotBeAdmin
+    );
+    validate_deposit_mint_extensions(&ctx.accounts.deposit_mint.to_account_info())?;
+
     let pool = &mut ctx.accounts.pool;
 
     // =========================================================================
@@ -119,6 +196,7 @@ pub fn handler_initialize_pool(ctx: Context<InitializePool>) -> Result<()> {
 
     pool.admin = ctx.accounts.admin.key();
     pool.bot_wallet = ctx.accounts.bot_wallet.key();
+    pool.guardian = config.guardian;
     pool.deposit_mint = ctx.accounts.deposit_mint.key();
     pool.share_mint = ctx.accounts.share_mint.key();
     pool.vault = ctx.accounts.vault.key();
@@ -133,24 +211,41 @@ pub fn handler_initialize_pool(ctx: Context<InitializePool>) -> Result<()> {
     pool.total_shares = 0;
     pool.total_profit = 0;
     pool.total_liquidations = 0;
+    pool.nonce = 0;
+    pool.emergency_withdrawn_total = 0;
 
     // =========================================================================
-    // Set default fee configuration (80/15/5 split)
+    // Set fee configuration (default 80/15/5 split)
     // =========================================================================
 
-    pool.depositor_fee_bps = DEPOSITOR_FEE_BPS;   // 80%
-    pool.staking_fee_bps = STAKING_FEE_BPS;       // 15%
-    pool.treasury_fee_bps = TREASURY_FEE_BPS;     // 5%
+    pool.depositor_fee_bps = config.depositor_fee_bps;
+    pool.staking_fee_bps = config.staking_fee_bps;
+    pool.treasury_fee_bps = config.treasury_fee_bps;
 
     // Validate that fees sum to 100%
     pool.validate_fees()?;
 
+    // No entry fee on deposits by default
+    pool.deposit_fee_bps = config.deposit_fee_bps;
+
+    // No exit fee on withdrawals by default
+    pool.withdrawal_fee_bps = config.withdrawal_fee_bps;
+
     // =========================================================================
     // Set pool status and configuration
     // =========================================================================
 
     pool.is_paused = false;
-    pool.max_pool_size = DEFAULT_POOL_SIZE;
+    pool.pause_flags = 0;
+    pool.max_pool_size = config.max_pool_size;
+    pool.max_deposit_per_user = config.max_deposit_per_user;
+    pool.min_profit_amount = config.min_profit_amount;
+    pool.is_disabled = false;
+    pool.admin_is_multisig = config.admin_is_multisig;
+
+    // Share price sanity band starts disabled unless configured
+    pool.min_share_price = config.min_share_price;
+    pool.max_share_price = config.max_share_price;
 
     // =========================================================================
     // Store PDA bumps
@@ -169,6 +264,9 @@ pub fn handler_initialize_pool(ctx: Context<InitializePool>) -> Result<()> {
     msg!("Vault: {}", pool.vault);
     msg!("Treasury: {}", pool.treasury);
     msg!("Staking Rewards: {}", pool.staking_rewards_vault);
+    msg!("Fees: depositor={}, staking={}, treasury={}, deposit={}",
+        pool.depositor_fee_bps, pool.staking_fee_bps, pool.treasury_fee_bps, pool.deposit_fee_bps);
+    msg!("Max Pool Size: {}", pool.max_pool_size);
 
     Ok(())
 }
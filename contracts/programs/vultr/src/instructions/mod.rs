@@ -10,8 +10,11 @@
 // =============================================================================
 
 // Core pool operations
+pub mod close_depositor;
 pub mod deposit;
+pub mod emergency_withdraw;
 pub mod initialize_pool;
+pub mod reset_depositor_stats;
 pub mod withdraw;
 
 // Profit recording (called by bot_wallet)
@@ -19,12 +22,21 @@ pub mod record_profit;
 
 // Admin operations
 pub mod admin;
+pub mod emergency_drain;
 pub mod update_pool_cap;
 
+// Read-only views (permissionless)
+pub mod views;
+
 // Re-export everything from each module
 pub use admin::*;
+pub use close_depositor::*;
 pub use deposit::*;
+pub use emergency_drain::*;
+pub use emergency_withdraw::*;
 pub use initialize_pool::*;
 pub use record_profit::*;
+pub use reset_depositor_stats::*;
 pub use update_pool_cap::*;
+pub use views::*;
 pub use withdraw::*;
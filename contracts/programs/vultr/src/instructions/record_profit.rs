@@ -13,7 +13,7 @@
 // =============================================================================
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 
 use crate::error::VultrError;
 use crate::state::Pool;
@@ -29,31 +29,38 @@ pub struct RecordProfit<'info> {
     /// The pool account
     #[account(
         mut,
+        constraint = pool.bot_wallet != Pubkey::default() @ VultrError::BotWalletRevoked,
         constraint = pool.bot_wallet == bot_wallet.key() @ VultrError::UnauthorizedBot,
-        constraint = !pool.is_paused @ VultrError::PoolPaused,
+        constraint = !pool.liquidations_paused() @ VultrError::PoolPaused,
     )]
     pub pool: Account<'info, Pool>,
 
+    /// The deposit token mint (needed for transfer_checked)
+    #[account(
+        constraint = deposit_mint.key() == pool.deposit_mint @ VultrError::InvalidDepositMint
+    )]
+    pub deposit_mint: InterfaceAccount<'info, Mint>,
+
     /// The pool's main vault - receives 80% (depositor share)
     #[account(
         mut,
         constraint = vault.key() == pool.vault @ VultrError::InvalidPDA,
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
 
     /// The staking rewards vault - receives 15% (for VLTR stakers)
     #[account(
         mut,
         constraint = staking_rewards_vault.key() == pool.staking_rewards_vault @ VultrError::InvalidPDA,
     )]
-    pub staking_rewards_vault: Account<'info, TokenAccount>,
+    pub staking_rewards_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// The treasury - receives 5% (protocol revenue)
     #[account(
         mut,
         constraint = treasury.key() == pool.treasury @ VultrError::InvalidPDA,
     )]
-    pub treasury: Account<'info, TokenAccount>,
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
 
     /// The bot's token account holding the profit to distribute
     /// This is where the liquidation profit sits before distribution
@@ -62,26 +69,35 @@ pub struct RecordProfit<'info> {
         mut,
         constraint = profit_source.owner == bot_wallet.key() @ VultrError::InvalidTokenAccountOwner,
     )]
-    pub profit_source: Account<'info, TokenAccount>,
+    pub profit_source: InterfaceAccount<'info, TokenAccount>,
 
     /// Token program
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Record profit from a liquidation and distribute fees
 ///
 /// # Arguments
 /// * `profit_amount` - Total profit from liquidation (in deposit token base units)
+/// * `expected_nonce` - Must equal pool.nonce (replay protection)
 ///
 /// # Fee Distribution
 /// * 80% to vault (increases share price for depositors)
 /// * 15% to staking_rewards_vault (for VLTR token stakers)
 /// * 5% to treasury (protocol revenue)
-pub fn handler_record_profit(ctx: Context<RecordProfit>, profit_amount: u64) -> Result<()> {
+pub fn handler_record_profit(
+    ctx: Context<RecordProfit>,
+    profit_amount: u64,
+    expected_nonce: u64,
+) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
 
+    // Reject duplicate or out-of-order submissions
+    require!(expected_nonce == pool.nonce, VultrError::StaleNonce);
+
     // Validate profit amount
     require!(profit_amount > 0, VultrError::InvalidProfit);
+    require!(profit_amount >= pool.min_profit_amount, VultrError::ProfitBelowMinimum);
 
     // Validate profit_source has sufficient balance before any transfers
     require!(
@@ -103,55 +119,73 @@ pub fn handler_record_profit(ctx: Context<RecordProfit>, profit_amount: u64) ->
 
     // Transfer depositor share (80%) to vault
     if depositor_share > 0 {
-        let transfer_to_vault = Transfer {
+        let vault_before = ctx.accounts.vault.amount;
+
+        let transfer_to_vault = TransferChecked {
             from: ctx.accounts.profit_source.to_account_info(),
+            mint: ctx.accounts.deposit_mint.to_account_info(),
             to: ctx.accounts.vault.to_account_info(),
             authority: ctx.accounts.bot_wallet.to_account_info(),
         };
-        token::transfer(
+        token_interface::transfer_checked(
             CpiContext::new(
                 ctx.accounts.token_program.to_account_info(),
                 transfer_to_vault,
             ),
             depositor_share,
+            ctx.accounts.deposit_mint.decimals,
         )?;
 
+        // Credit what the vault actually received, which a Token-2022
+        // transfer fee can leave below depositor_share
+        ctx.accounts.vault.reload()?;
+        let received = ctx
+            .accounts
+            .vault
+            .amount
+            .checked_sub(vault_before)
+            .ok_or(VultrError::MathUnderflow)?;
+
         // Update pool's total_deposits to reflect the profit added
         pool.total_deposits = pool
             .total_deposits
-            .checked_add(depositor_share)
+            .checked_add(received)
             .ok_or(VultrError::MathOverflow)?;
     }
 
     // Transfer staking share (15%) to staking_rewards_vault
     if staking_share > 0 {
-        let transfer_to_staking = Transfer {
+        let transfer_to_staking = TransferChecked {
             from: ctx.accounts.profit_source.to_account_info(),
+            mint: ctx.accounts.deposit_mint.to_account_info(),
             to: ctx.accounts.staking_rewards_vault.to_account_info(),
             authority: ctx.accounts.bot_wallet.to_account_info(),
         };
-        token::transfer(
+        token_interface::transfer_checked(
             CpiContext::new(
                 ctx.accounts.token_program.to_account_info(),
                 transfer_to_staking,
             ),
             staking_share,
+            ctx.accounts.deposit_mint.decimals,
         )?;
     }
 
     // Transfer treasury share (5%) to treasury
     if treasury_share > 0 {
-        let transfer_to_treasury = Transfer {
+        let transfer_to_treasury = TransferChecked {
             from: ctx.accounts.profit_source.to_account_info(),
+            mint: ctx.accounts.deposit_mint.to_account_info(),
             to: ctx.accounts.treasury.to_account_info(),
             authority: ctx.accounts.bot_wallet.to_account_info(),
         };
-        token::transfer(
+        token_interface::transfer_checked(
             CpiContext::new(
                 ctx.accounts.token_program.to_account_info(),
                 transfer_to_treasury,
             ),
             treasury_share,
+            ctx.accounts.deposit_mint.decimals,
         )?;
     }
 
@@ -166,6 +200,11 @@ pub fn handler_record_profit(ctx: Context<RecordProfit>, profit_amount: u64) ->
         .checked_add(1)
         .ok_or(VultrError::MathOverflow)?;
 
+    pool.nonce = pool
+        .nonce
+        .checked_add(1)
+        .ok_or(VultrError::MathOverflow)?;
+
     msg!(
         "Profit recorded successfully. Total profit: {}, Total liquidations: {}",
         pool.total_profit,
@@ -0,0 +1,75 @@
+// =============================================================================
+// Reset Depositor Stats Instruction
+// =============================================================================
+// Lets a depositor clear their cumulative accounting after a full exit.
+//
+// Depositor keeps lifetime totals (total_deposited, total_withdrawn, ...).
+// Once a user has burned all of their shares those totals no longer describe
+// a live position, and a returning user would otherwise carry them into
+// PnL and per-user limit calculations.
+//
+// Only the owner can reset, and only while their share ATA is empty. The
+// zero-balance check covers that one account: shares moved to any other
+// token account are not seen. Since a reset also clears total_deposited,
+// which backs max_deposit_per_user, the cap is only as strong as this check.
+// =============================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::constants::*;
+use crate::error::VultrError;
+use crate::state::{Depositor, Pool};
+
+/// Accounts required for the reset_depositor_stats instruction
+#[derive(Accounts)]
+pub struct ResetDepositorStats<'info> {
+    /// The depositor resetting their own stats
+    pub owner: Signer<'info>,
+
+    /// The pool the depositor belongs to
+    #[account(
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// The owner's depositor state account
+    #[account(
+        mut,
+        seeds = [DEPOSITOR_SEED, pool.key().as_ref(), owner.key().as_ref()],
+        bump = depositor_account.bump,
+        constraint = depositor_account.owner == owner.key() @ VultrError::Unauthorized
+    )]
+    pub depositor_account: Account<'info, Depositor>,
+
+    /// The owner's share ATA - must be empty
+    #[account(
+        constraint = owner_share_account.key() == get_associated_token_address_with_program_id(
+            &owner.key(),
+            &pool.share_mint,
+            owner_share_account.to_account_info().owner,
+        ) @ VultrError::ShareAccountNotAssociated,
+        constraint = owner_share_account.mint == pool.share_mint @ VultrError::InvalidShareMint,
+        constraint = owner_share_account.owner == owner.key() @ VultrError::InvalidTokenAccountOwner,
+        constraint = owner_share_account.amount == 0 @ VultrError::SharesOutstanding
+    )]
+    pub owner_share_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+/// Handler for reset_depositor_stats
+pub fn handler_reset_depositor_stats(ctx: Context<ResetDepositorStats>) -> Result<()> {
+    let depositor_account = &mut ctx.accounts.depositor_account;
+
+    msg!(
+        "Resetting depositor stats: {} deposited, {} withdrawn over {} deposits",
+        depositor_account.total_deposited,
+        depositor_account.total_withdrawn,
+        depositor_account.deposit_count
+    );
+
+    depositor_account.reset_stats();
+
+    Ok(())
+}
@@ -8,28 +8,32 @@
 // - Gradually raise cap as liquidation volume grows
 // - Ensure pool size matches available liquidation opportunities
 //
+// Lowering the cap only restricts new deposits, so update_pool_cap applies it
+// immediately. Raising it lets more capital into the pool and goes through the
+// same 24h propose/finalize/cancel timelock as the fee changes.
+//
 // Security: Only callable by pool admin
 // =============================================================================
 
 use anchor_lang::prelude::*;
 use crate::state::Pool;
 use crate::error::VultrError;
-use crate::constants::MAX_POOL_SIZE;
+use crate::constants::{ADMIN_TIMELOCK_SECONDS, MAX_POOL_SIZE, PENDING_CHANGE_EXPIRY_SECONDS};
 
-/// Update the maximum pool size cap
+/// Lower the maximum pool size cap (takes effect immediately)
 ///
 /// # Arguments
 /// * `new_cap` - New maximum pool size in base units (e.g., USDC with 6 decimals)
 ///
 /// # Security
 /// - Only admin can call this
-/// - New cap cannot exceed global MAX_POOL_SIZE (1B USDC)
+/// - New cap must be below the current cap (raises go through propose_pool_cap)
 /// - New cap must be >= current total_deposits (cannot reduce below current TVL)
 ///
 /// # Example
-/// ```
-/// // Raise cap from 500K to 1M USDC
-/// update_pool_cap(ctx, 1_000_000_000_000) // 1M * 10^6
+/// ```ignore
+/// // Lower cap from 1M to 500K USDC
+/// update_pool_cap(ctx, 500_000_000_000) // 500K * 10^6
 /// ```
 #[derive(Accounts)]
 pub struct UpdatePoolCap<'info> {
@@ -80,6 +84,12 @@ pub fn handler_update_pool_cap(
         VultrError::InvalidPoolCap
     );
 
+    // Raising the cap must be announced 24h ahead
+    require!(
+        new_cap < old_cap,
+        VultrError::PoolCapIncreaseRequiresTimelock
+    );
+
     // =========================================================================
     // Update Pool Cap
     // =========================================================================
@@ -98,3 +108,74 @@ pub fn handler_update_pool_cap(
 
     Ok(())
 }
+
+// =============================================================================
+// Pool Cap Increase with Timelock
+// =============================================================================
+
+/// Propose raising the pool cap (24-hour timelock)
+pub fn handler_propose_pool_cap(
+    ctx: Context<UpdatePoolCap>,
+    new_cap: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(
+        new_cap <= MAX_POOL_SIZE,
+        VultrError::ExceedsMaxPoolSize
+    );
+
+    // Only increases need the timelock; decreases use update_pool_cap
+    require!(
+        new_cap > pool.max_pool_size,
+        VultrError::InvalidPoolCap
+    );
+
+    pool.pending_max_pool_size = new_cap;
+    pool.pool_cap_change_timestamp = clock.unix_timestamp;
+
+    msg!("Pool cap increase PROPOSED: {} -> {}", pool.max_pool_size, new_cap);
+    msg!("Timelock expires at: {} (in {} seconds)",
+        clock.unix_timestamp + ADMIN_TIMELOCK_SECONDS, ADMIN_TIMELOCK_SECONDS);
+
+    Ok(())
+}
+
+/// Finalize a pool cap increase after timelock expires
+pub fn handler_finalize_pool_cap(ctx: Context<UpdatePoolCap>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(pool.pool_cap_change_timestamp != 0, VultrError::NoPendingChange);
+
+    // Check timelock has expired
+    let elapsed = clock.unix_timestamp - pool.pool_cap_change_timestamp;
+    require!(elapsed >= ADMIN_TIMELOCK_SECONDS, VultrError::TimelockNotExpired);
+
+    // Check change hasn't expired (7 days max)
+    require!(elapsed <= PENDING_CHANGE_EXPIRY_SECONDS, VultrError::TimelockExpired);
+
+    let old_cap = pool.max_pool_size;
+    pool.max_pool_size = pool.pending_max_pool_size;
+    pool.pending_max_pool_size = 0;
+    pool.pool_cap_change_timestamp = 0;
+
+    msg!("Pool cap increase FINALIZED: {} -> {}", old_cap, pool.max_pool_size);
+
+    Ok(())
+}
+
+/// Cancel a pending pool cap increase
+pub fn handler_cancel_pool_cap(ctx: Context<UpdatePoolCap>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(pool.pool_cap_change_timestamp != 0, VultrError::NoPendingChangeToCancel);
+
+    pool.pending_max_pool_size = 0;
+    pool.pool_cap_change_timestamp = 0;
+
+    msg!("Pool cap increase CANCELLED");
+
+    Ok(())
+}
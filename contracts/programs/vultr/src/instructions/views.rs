@@ -0,0 +1,394 @@
+// =============================================================================
+// View Instructions
+// =============================================================================
+// Read-only, permissionless instructions that return data to the caller.
+//
+// These never mutate state and require no signer, so block explorers,
+// portfolio trackers and other programs can query them freely (e.g. via
+// simulateTransaction / `.view()` in the Anchor TS client).
+//
+// Results are returned through Anchor's return-data mechanism, which calls
+// `set_return_data` with the Borsh-serialized return value.
+// =============================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::constants::*;
+use crate::error::VultrError;
+use crate::state::{Depositor, Pool, WithdrawalRecord};
+
+// =============================================================================
+// Depositor Position
+// =============================================================================
+
+/// A depositor's position, as returned by get_depositor_position
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DepositorPosition {
+    /// Total share tokens ever minted to this depositor (historical)
+    pub shares_minted: u64,
+
+    /// Cumulative deposit tokens deposited
+    pub total_deposited: u64,
+
+    /// Cumulative deposit tokens withdrawn
+    pub total_withdrawn: u64,
+
+    /// Value of the depositor's current share balance at the live share price
+    pub current_value: u64,
+
+    /// total_withdrawn - total_deposited (excludes unrealized gains)
+    pub realized_pnl: i64,
+}
+
+/// Accounts required for the get_depositor_position view
+#[derive(Accounts)]
+pub struct GetDepositorPosition<'info> {
+    /// The pool the depositor belongs to
+    #[account(
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// The depositor state account being queried (any owner)
+    #[account(
+        seeds = [DEPOSITOR_SEED, pool.key().as_ref(), depositor_account.owner.as_ref()],
+        bump = depositor_account.bump,
+    )]
+    pub depositor_account: Account<'info, Depositor>,
+
+    /// The depositor's share token account
+    /// Needed because current share balance lives in the token account,
+    /// not in the Depositor PDA
+    #[account(
+        constraint = owner_share_account.mint == pool.share_mint @ VultrError::InvalidShareMint,
+        constraint = owner_share_account.owner == depositor_account.owner @ VultrError::InvalidTokenAccountOwner,
+    )]
+    pub owner_share_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+/// Handler for get_depositor_position
+///
+/// current_value is computed from the owner's share balance using the same
+/// formula as withdraw, so it reflects all profit recorded so far.
+pub fn handler_get_depositor_position(
+    ctx: Context<GetDepositorPosition>,
+) -> Result<DepositorPosition> {
+    let depositor = &ctx.accounts.depositor_account;
+    let current_value = current_share_value(
+        &ctx.accounts.pool,
+        ctx.accounts.owner_share_account.amount,
+    )?;
+
+    Ok(DepositorPosition {
+        shares_minted: depositor.shares_minted,
+        total_deposited: depositor.total_deposited,
+        total_withdrawn: depositor.total_withdrawn,
+        current_value,
+        realized_pnl: depositor.realized_pnl(),
+    })
+}
+
+/// Value of `share_balance` at the live share price (0 for an empty pool)
+fn current_share_value(pool: &Pool, share_balance: u64) -> Result<u64> {
+    if share_balance == 0 || pool.total_shares == 0 {
+        Ok(0)
+    } else {
+        pool.calculate_withdrawal_amount(share_balance)
+    }
+}
+
+// =============================================================================
+// Depositor PnL
+// =============================================================================
+
+/// A depositor's profit/loss, as returned by get_depositor_pnl
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DepositorPnl {
+    /// total_withdrawn - total_deposited
+    pub realized_pnl: i64,
+
+    /// Value of the depositor's current share balance at the live share price
+    pub unrealized_value: u64,
+
+    /// realized_pnl + unrealized_value: gain or loss if the depositor exited
+    /// now (before any withdrawal fee)
+    pub total_pnl: i64,
+}
+
+/// Handler for get_depositor_pnl
+///
+/// Takes the same accounts as get_depositor_position. Shares received by
+/// transfer count toward unrealized_value but not toward cost basis.
+pub fn handler_get_depositor_pnl(ctx: Context<GetDepositorPosition>) -> Result<DepositorPnl> {
+    let depositor = &ctx.accounts.depositor_account;
+    let unrealized_value = current_share_value(
+        &ctx.accounts.pool,
+        ctx.accounts.owner_share_account.amount,
+    )?;
+
+    Ok(DepositorPnl {
+        realized_pnl: depositor.realized_pnl(),
+        unrealized_value,
+        total_pnl: depositor.total_pnl(unrealized_value)?,
+    })
+}
+
+// =============================================================================
+// Withdrawal History
+// =============================================================================
+
+/// A depositor's recent withdrawals, as returned by get_withdrawal_history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WithdrawalHistory {
+    /// Total withdrawals ever recorded (may exceed records.len())
+    pub withdrawals_recorded: u32,
+
+    /// Up to WITHDRAWAL_HISTORY_LEN most recent withdrawals, oldest first
+    pub records: Vec<WithdrawalRecord>,
+}
+
+/// Accounts required for the get_withdrawal_history view
+#[derive(Accounts)]
+pub struct GetWithdrawalHistory<'info> {
+    /// The pool the depositor belongs to
+    #[account(
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// The depositor state account being queried (any owner)
+    #[account(
+        seeds = [DEPOSITOR_SEED, pool.key().as_ref(), depositor_account.owner.as_ref()],
+        bump = depositor_account.bump,
+    )]
+    pub depositor_account: Account<'info, Depositor>,
+}
+
+/// Handler for get_withdrawal_history
+pub fn handler_get_withdrawal_history(
+    ctx: Context<GetWithdrawalHistory>,
+) -> Result<WithdrawalHistory> {
+    let depositor = &ctx.accounts.depositor_account;
+
+    Ok(WithdrawalHistory {
+        withdrawals_recorded: depositor.withdrawals_recorded,
+        records: depositor.recent_withdrawals(),
+    })
+}
+
+// =============================================================================
+// Deposit Quote
+// =============================================================================
+
+/// The result of a hypothetical deposit, as returned by quote_deposit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DepositQuote {
+    /// Share tokens the deposit would mint right now
+    pub shares_to_mint: u64,
+
+    /// Entry fee that would go to the treasury
+    pub deposit_fee: u64,
+
+    /// Amount that would reach the vault (amount - deposit_fee)
+    pub net_amount: u64,
+
+    /// Current share price, scaled by SHARE_PRICE_PRECISION (1e6)
+    pub share_price: u64,
+}
+
+/// Accounts required for the quote_deposit view
+#[derive(Accounts)]
+pub struct QuoteDeposit<'info> {
+    /// The pool to quote against
+    #[account(
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Handler for quote_deposit
+///
+/// Uses Pool::quote_deposit, the same path handler_deposit takes, so it
+/// fails with the same error (BelowMinimumDeposit, ShareAmountZero,
+/// ExceedsMaxPoolSize, ...) the real deposit would.
+pub fn handler_quote_deposit(ctx: Context<QuoteDeposit>, amount: u64) -> Result<DepositQuote> {
+    let pool = &ctx.accounts.pool;
+    let (deposit_fee, net_amount, shares_to_mint) = pool.quote_deposit(amount)?;
+
+    Ok(DepositQuote {
+        shares_to_mint,
+        deposit_fee,
+        net_amount,
+        share_price: pool.share_price()?,
+    })
+}
+
+// =============================================================================
+// Withdraw Quote
+// =============================================================================
+
+/// The result of a hypothetical withdrawal, as returned by quote_withdraw
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WithdrawQuote {
+    /// Deposit tokens the shares would redeem for right now, net of the
+    /// withdrawal fee
+    pub amount: u64,
+
+    /// Withdrawal fee that would stay in the pool
+    pub withdrawal_fee: u64,
+
+    /// Current vault balance
+    pub vault_balance: u64,
+
+    /// Whether the vault can pay `amount` right now; false means withdrawals
+    /// are temporarily limited and the real withdraw would fail with
+    /// InsufficientBalance
+    pub vault_sufficient: bool,
+
+    /// Current share price, scaled by SHARE_PRICE_PRECISION (1e6)
+    pub share_price: u64,
+}
+
+/// Accounts required for the quote_withdraw view
+#[derive(Accounts)]
+pub struct QuoteWithdraw<'info> {
+    /// The pool to quote against
+    #[account(
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// The pool's vault, to report available liquidity
+    #[account(
+        seeds = [VAULT_SEED, pool.key().as_ref()],
+        bump = pool.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+}
+
+/// Handler for quote_withdraw
+///
+/// Uses Pool::quote_withdrawal, the same path handler_withdraw takes. A
+/// short vault is reported through vault_sufficient rather than an error.
+pub fn handler_quote_withdraw(ctx: Context<QuoteWithdraw>, shares_to_burn: u64) -> Result<WithdrawQuote> {
+    let pool = &ctx.accounts.pool;
+    let (amount, withdrawal_fee) = pool.quote_withdrawal(shares_to_burn)?;
+    let vault_balance = ctx.accounts.vault.amount;
+
+    Ok(WithdrawQuote {
+        amount,
+        withdrawal_fee,
+        vault_balance,
+        vault_sufficient: vault_balance >= amount,
+        share_price: pool.share_price()?,
+    })
+}
+
+// =============================================================================
+// Fee Distribution Preview
+// =============================================================================
+
+/// The fee split for a profit amount, as returned by preview_fee_distribution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FeeDistribution {
+    /// Share added to the vault for depositors
+    pub depositor_share: u64,
+
+    /// Share sent to the VLTR staking rewards vault
+    pub staking_share: u64,
+
+    /// Share sent to the treasury (absorbs rounding dust)
+    pub treasury_share: u64,
+}
+
+/// Accounts required for the preview_fee_distribution view
+#[derive(Accounts)]
+pub struct PreviewFeeDistribution<'info> {
+    /// The pool whose current fee configuration is used
+    #[account(
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Handler for preview_fee_distribution
+///
+/// Uses the same calculate_fee_distribution as record_profit, so the three
+/// slices always sum exactly to `profit`.
+pub fn handler_preview_fee_distribution(
+    ctx: Context<PreviewFeeDistribution>,
+    profit: u64,
+) -> Result<FeeDistribution> {
+    let (depositor_share, staking_share, treasury_share) =
+        ctx.accounts.pool.calculate_fee_distribution(profit)?;
+
+    Ok(FeeDistribution {
+        depositor_share,
+        staking_share,
+        treasury_share,
+    })
+}
+
+// =============================================================================
+// Pool Stats
+// =============================================================================
+
+/// Pool-wide figures, as returned by get_pool_stats
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Current share price, scaled by SHARE_PRICE_PRECISION (1e6)
+    pub share_price: u64,
+
+    /// Deposit tokens accounted to depositors (TVL)
+    pub total_deposits: u64,
+
+    /// Share tokens outstanding
+    pub total_shares: u64,
+
+    /// Cumulative profit recorded (lifetime)
+    pub total_profit: u64,
+
+    /// total_deposits as a share of max_pool_size, in basis points
+    pub utilization_bps: u16,
+
+    /// Whether the pool is paused
+    pub is_paused: bool,
+
+    /// Granular pause bits (see PAUSE_DEPOSITS and friends)
+    pub pause_flags: u8,
+}
+
+/// Accounts required for the get_pool_stats view
+#[derive(Accounts)]
+pub struct GetPoolStats<'info> {
+    /// The pool to report on
+    #[account(
+        seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Handler for get_pool_stats
+///
+/// Uses the same Pool::share_price as deposit and withdraw, so integrators
+/// no longer need to derive it from total_deposits and total_shares.
+pub fn handler_get_pool_stats(ctx: Context<GetPoolStats>) -> Result<PoolStats> {
+    let pool = &ctx.accounts.pool;
+
+    Ok(PoolStats {
+        share_price: pool.share_price()?,
+        total_deposits: pool.total_deposits,
+        total_shares: pool.total_shares,
+        total_profit: pool.total_profit,
+        utilization_bps: pool.utilization_bps()?,
+        is_paused: pool.is_paused,
+        pause_flags: pool.pause_flags,
+    })
+}
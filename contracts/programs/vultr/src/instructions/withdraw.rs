@@ -17,10 +17,11 @@
 // =============================================================================
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked};
 
 use crate::constants::*;
 use crate::error::VultrError;
+use crate::events::WithdrawEvent;
 use crate::state::{Depositor, Pool};
 
 /// Accounts required for the withdraw instruction
@@ -44,7 +45,7 @@ pub struct Withdraw<'info> {
         mut,
         seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
         bump = pool.bump,
-        constraint = !pool.is_paused @ VultrError::PoolPaused
+        constraint = !pool.withdrawals_paused() @ VultrError::PoolPaused
     )]
     pub pool: Account<'info, Pool>,
 
@@ -65,7 +66,7 @@ pub struct Withdraw<'info> {
     #[account(
         constraint = deposit_mint.key() == pool.deposit_mint @ VultrError::InvalidDepositMint
     )]
-    pub deposit_mint: Account<'info, Mint>,
+    pub deposit_mint: InterfaceAccount<'info, Mint>,
 
     /// The share token mint (VLTR)
     /// Program will burn shares from user
@@ -74,7 +75,7 @@ pub struct Withdraw<'info> {
         seeds = [SHARE_MINT_SEED, pool.key().as_ref()],
         bump = pool.share_mint_bump
     )]
-    pub share_mint: Account<'info, Mint>,
+    pub share_mint: InterfaceAccount<'info, Mint>,
 
     // =========================================================================
     // Token Accounts
@@ -86,7 +87,7 @@ pub struct Withdraw<'info> {
         constraint = user_deposit_account.mint == deposit_mint.key() @ VultrError::InvalidDepositMint,
         constraint = user_deposit_account.owner == withdrawer.key() @ VultrError::InvalidTokenAccountOwner
     )]
-    pub user_deposit_account: Account<'info, TokenAccount>,
+    pub user_deposit_account: InterfaceAccount<'info, TokenAccount>,
 
     /// User's share token account (source of shares to burn)
     #[account(
@@ -94,7 +95,7 @@ pub struct Withdraw<'info> {
         constraint = user_share_account.mint == share_mint.key() @ VultrError::InvalidShareMint,
         constraint = user_share_account.owner == withdrawer.key() @ VultrError::InvalidTokenAccountOwner
     )]
-    pub user_share_account: Account<'info, TokenAccount>,
+    pub user_share_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Pool's vault (source of withdrawal tokens)
     #[account(
@@ -102,13 +103,13 @@ pub struct Withdraw<'info> {
         seeds = [VAULT_SEED, pool.key().as_ref()],
         bump = pool.vault_bump
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
 
     // =========================================================================
     // Programs
     // =========================================================================
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Handler for the withdraw instruction
@@ -123,6 +124,9 @@ pub fn handler_withdraw(ctx: Context<Withdraw>, shares_to_burn: u64, min_amount_
     // =========================================================================
 
     // Check amount is greater than 0
+    // There is deliberately no MIN_SHARES_MINTED floor here: partial
+    // withdrawals can leave a dust balance below it, and that dust must
+    // always be redeemable
     require!(shares_to_burn > 0, VultrError::InvalidAmount);
 
     // Check user has sufficient shares
@@ -131,18 +135,15 @@ pub fn handler_withdraw(ctx: Context<Withdraw>, shares_to_burn: u64, min_amount_
         VultrError::InsufficientShares
     );
 
-    // Check pool has shares to burn
-    require!(
-        ctx.accounts.pool.total_shares >= shares_to_burn,
-        VultrError::InsufficientShares
-    );
-
     // =========================================================================
     // Calculate Withdrawal Amount
     // =========================================================================
+    // Pool share supply and the sanity band are checked by
+    // Pool::quote_withdrawal, which the quote_withdraw view also uses.
+    // withdrawal_amount is net of the withdrawal fee; the fee never leaves
+    // the vault
 
-    let pool = &ctx.accounts.pool;
-    let withdrawal_amount = pool.calculate_withdrawal_amount(shares_to_burn)?;
+    let (withdrawal_amount, withdrawal_fee) = ctx.accounts.pool.quote_withdrawal(shares_to_burn)?;
 
     // Check vault has sufficient funds
     require!(
@@ -160,8 +161,9 @@ pub fn handler_withdraw(ctx: Context<Withdraw>, shares_to_burn: u64, min_amount_
     }
 
     msg!(
-        "Withdrawing {} tokens for {} shares",
+        "Withdrawing {} tokens ({} fee kept in pool) for {} shares",
         withdrawal_amount,
+        withdrawal_fee,
         shares_to_burn
     );
 
@@ -180,7 +182,7 @@ pub fn handler_withdraw(ctx: Context<Withdraw>, shares_to_burn: u64, min_amount_
     );
 
     // Execute the burn
-    token::burn(burn_ctx, shares_to_burn)?;
+    token_interface::burn(burn_ctx, shares_to_burn)?;
 
     // =========================================================================
     // Transfer Deposit Tokens: Vault -> User
@@ -198,8 +200,9 @@ pub fn handler_withdraw(ctx: Context<Withdraw>, shares_to_burn: u64, min_amount_
     // Create the transfer instruction with PDA signer
     let transfer_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.vault.to_account_info(),
+            mint: ctx.accounts.deposit_mint.to_account_info(),
             to: ctx.accounts.user_deposit_account.to_account_info(),
             authority: ctx.accounts.pool.to_account_info(),
         },
@@ -207,7 +210,11 @@ pub fn handler_withdraw(ctx: Context<Withdraw>, shares_to_burn: u64, min_amount_
     );
 
     // Execute the transfer
-    token::transfer(transfer_ctx, withdrawal_amount)?;
+    token_interface::transfer_checked(
+        transfer_ctx,
+        withdrawal_amount,
+        ctx.accounts.deposit_mint.decimals,
+    )?;
 
     // =========================================================================
     // Update Pool State
@@ -215,6 +222,8 @@ pub fn handler_withdraw(ctx: Context<Withdraw>, shares_to_burn: u64, min_amount_
 
     let pool = &mut ctx.accounts.pool;
 
+    // Only the amount paid out leaves total_deposits, so the withdrawal fee
+    // accrues to the remaining shareholders
     pool.total_deposits = pool
         .total_deposits
         .checked_sub(withdrawal_amount)
@@ -247,59 +256,49 @@ pub fn handler_withdraw(ctx: Context<Withdraw>, shares_to_burn: u64, min_amount_
     msg!("New pool total deposits: {}", pool.total_deposits);
     msg!("New pool total shares: {}", pool.total_shares);
 
+    emit!(WithdrawEvent {
+        pool: pool.key(),
+        withdrawer: ctx.accounts.withdrawer.key(),
+        shares_burned: shares_to_burn,
+        amount: withdrawal_amount,
+        share_price: pool.share_price()?,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
 
 // =============================================================================
-// SECURITY FIX-6: Emergency Withdrawal
+// Withdraw Without Depositor Record
 // =============================================================================
-// Allows users to withdraw their funds if the pool has been paused for > 7 days.
-// This prevents admins from indefinitely locking user funds.
+// Share tokens are freely transferable, but `withdraw` requires the caller's
+// own Depositor PDA, which only exists for addresses that deposited. This
+// path lets ANY share holder redeem: it burns the caller's shares and pays out
+// at the current share price, skipping the per-depositor statistics update.
 // =============================================================================
 
-/// Accounts required for emergency_withdraw instruction
+/// Accounts required for the withdraw_no_record instruction
 #[derive(Accounts)]
-pub struct EmergencyWithdraw<'info> {
-    // =========================================================================
-    // Signers
-    // =========================================================================
-
-    /// The user withdrawing tokens
+pub struct WithdrawNoRecord<'info> {
+    /// The share holder redeeming tokens
     /// Must sign to authorize share burning
     #[account(mut)]
     pub withdrawer: Signer<'info>,
 
-    // =========================================================================
-    // Pool Accounts
-    // =========================================================================
-
-    /// The pool to withdraw from (must be paused for > 7 days)
+    /// The pool to withdraw from
     #[account(
         mut,
         seeds = [POOL_SEED, pool.deposit_mint.as_ref()],
         bump = pool.bump,
-        // Note: We check is_paused and pause_timestamp in the handler
+        constraint = !pool.withdrawals_paused() @ VultrError::PoolPaused
     )]
     pub pool: Account<'info, Pool>,
 
-    /// The withdrawer's depositor state account
-    #[account(
-        mut,
-        seeds = [DEPOSITOR_SEED, pool.key().as_ref(), withdrawer.key().as_ref()],
-        bump = depositor_account.bump,
-        constraint = depositor_account.owner == withdrawer.key() @ VultrError::Unauthorized
-    )]
-    pub depositor_account: Account<'info, Depositor>,
-
-    // =========================================================================
-    // Token Mints
-    // =========================================================================
-
     /// The deposit token mint (e.g., USDC)
     #[account(
         constraint = deposit_mint.key() == pool.deposit_mint @ VultrError::InvalidDepositMint
     )]
-    pub deposit_mint: Account<'info, Mint>,
+    pub deposit_mint: InterfaceAccount<'info, Mint>,
 
     /// The share token mint (VLTR)
     /// Program will burn shares from user
@@ -308,11 +307,7 @@ pub struct EmergencyWithdraw<'info> {
         seeds = [SHARE_MINT_SEED, pool.key().as_ref()],
         bump = pool.share_mint_bump
     )]
-    pub share_mint: Account<'info, Mint>,
-
-    // =========================================================================
-    // Token Accounts
-    // =========================================================================
+    pub share_mint: InterfaceAccount<'info, Mint>,
 
     /// User's deposit token account (destination for withdrawn tokens)
     #[account(
@@ -320,7 +315,7 @@ pub struct EmergencyWithdraw<'info> {
         constraint = user_deposit_account.mint == deposit_mint.key() @ VultrError::InvalidDepositMint,
         constraint = user_deposit_account.owner == withdrawer.key() @ VultrError::InvalidTokenAccountOwner
     )]
-    pub user_deposit_account: Account<'info, TokenAccount>,
+    pub user_deposit_account: InterfaceAccount<'info, TokenAccount>,
 
     /// User's share token account (source of shares to burn)
     #[account(
@@ -328,7 +323,7 @@ pub struct EmergencyWithdraw<'info> {
         constraint = user_share_account.mint == share_mint.key() @ VultrError::InvalidShareMint,
         constraint = user_share_account.owner == withdrawer.key() @ VultrError::InvalidTokenAccountOwner
     )]
-    pub user_share_account: Account<'info, TokenAccount>,
+    pub user_share_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Pool's vault (source of withdrawal tokens)
     #[account(
@@ -336,58 +331,41 @@ pub struct EmergencyWithdraw<'info> {
         seeds = [VAULT_SEED, pool.key().as_ref()],
         bump = pool.vault_bump
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
 
-    // =========================================================================
-    // Programs
-    // =========================================================================
-
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-/// Handler for emergency_withdraw instruction
+/// Handler for withdraw_no_record instruction
 ///
-/// Allows withdrawal when pool is paused for > 7 days
+/// Same pricing and pool accounting as `withdraw`, but no Depositor
+/// account is read or updated.
 ///
 /// # Arguments
 /// * `ctx` - The instruction context with all accounts
 /// * `shares_to_burn` - Number of share tokens to burn
-pub fn handler_emergency_withdraw(ctx: Context<EmergencyWithdraw>, shares_to_burn: u64) -> Result<()> {
-    let pool = &ctx.accounts.pool;
-    let clock = Clock::get()?;
-
-    // =========================================================================
-    // SECURITY FIX-6: Validate emergency withdrawal conditions
-    // =========================================================================
-
-    // Pool must be paused
-    require!(pool.is_paused, VultrError::PoolNotPaused);
-
-    // Pool must have been paused for at least 7 days
-    let paused_duration = clock.unix_timestamp - pool.pause_timestamp;
-    require!(
-        paused_duration >= EMERGENCY_TIMELOCK_SECONDS,
-        VultrError::EmergencyTimelockNotExpired
-    );
-
-    msg!("Emergency withdrawal triggered - pool paused for {} seconds (minimum: {})",
-        paused_duration, EMERGENCY_TIMELOCK_SECONDS);
-
-    // =========================================================================
-    // Standard withdrawal logic (same as regular withdraw)
-    // =========================================================================
-
+/// * `min_amount_out` - Minimum tokens to receive (slippage protection, 0 to skip)
+pub fn handler_withdraw_no_record(
+    ctx: Context<WithdrawNoRecord>,
+    shares_to_burn: u64,
+    min_amount_out: u64,
+) -> Result<()> {
     require!(shares_to_burn > 0, VultrError::InvalidAmount);
     require!(
         ctx.accounts.user_share_account.amount >= shares_to_burn,
         VultrError::InsufficientShares
     );
-    require!(
-        ctx.accounts.pool.total_shares >= shares_to_burn,
-        VultrError::InsufficientShares
-    );
 
-    let withdrawal_amount = pool.calculate_withdrawal_amount(shares_to_burn)?;
+    // Same pricing, sanity band and withdrawal fee as withdraw
+    let (withdrawal_amount, _withdrawal_fee) = ctx.accounts.pool.quote_withdrawal(shares_to_burn)?;
+
+    // Slippage protection, as in withdraw
+    if min_amount_out > 0 {
+        require!(
+            withdrawal_amount >= min_amount_out,
+            VultrError::SlippageExceeded
+        );
+    }
 
     require!(
         ctx.accounts.vault.amount >= withdrawal_amount,
@@ -403,7 +381,7 @@ pub fn handler_emergency_withdraw(ctx: Context<EmergencyWithdraw>, shares_to_bur
             authority: ctx.accounts.withdrawer.to_account_info(),
         },
     );
-    token::burn(burn_ctx, shares_to_burn)?;
+    token_interface::burn(burn_ctx, shares_to_burn)?;
 
     // Transfer tokens from vault to user
     let deposit_mint_key = ctx.accounts.deposit_mint.key();
@@ -416,14 +394,19 @@ pub fn handler_emergency_withdraw(ctx: Context<EmergencyWithdraw>, shares_to_bur
 
     let transfer_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.vault.to_account_info(),
+            mint: ctx.accounts.deposit_mint.to_account_info(),
             to: ctx.accounts.user_deposit_account.to_account_info(),
             authority: ctx.accounts.pool.to_account_info(),
         },
         signer_seeds,
     );
-    token::transfer(transfer_ctx, withdrawal_amount)?;
+    token_interface::transfer_checked(
+        transfer_ctx,
+        withdrawal_amount,
+        ctx.accounts.deposit_mint.decimals,
+    )?;
 
     // Update pool state
     let pool = &mut ctx.accounts.pool;
@@ -436,13 +419,18 @@ pub fn handler_emergency_withdraw(ctx: Context<EmergencyWithdraw>, shares_to_bur
         .checked_sub(shares_to_burn)
         .ok_or(VultrError::MathUnderflow)?;
 
-    // Update depositor account
-    let depositor_account = &mut ctx.accounts.depositor_account;
-    depositor_account.record_withdrawal(withdrawal_amount, clock.unix_timestamp)?;
-
-    msg!("EMERGENCY WITHDRAWAL successful!");
+    msg!("Withdrawal (no depositor record) successful!");
     msg!("Shares burned: {}", shares_to_burn);
     msg!("Amount withdrawn: {}", withdrawal_amount);
 
+    emit!(WithdrawEvent {
+        pool: pool.key(),
+        withdrawer: ctx.accounts.withdrawer.key(),
+        shares_burned: shares_to_burn,
+        amount: withdrawal_amount,
+        share_price: pool.share_price()?,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
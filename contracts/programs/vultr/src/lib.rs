@@ -15,6 +15,7 @@
 // Module declarations - these tell Rust where to find our code
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -24,6 +25,7 @@ use anchor_lang::prelude::*;
 // Re-export our modules so users of this crate can access them
 pub use constants::*;
 pub use error::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -60,6 +62,18 @@ pub mod vultr {
         instructions::initialize_pool::handler_initialize_pool(ctx)
     }
 
+    /// Initialize a new pool with every configurable field set up front
+    ///
+    /// Uses the same accounts as initialize_pool. Because the pool is not
+    /// live yet, fees, cap, guardian and sanity band are applied directly
+    /// instead of going through their timelocks.
+    ///
+    /// # Arguments
+    /// * `config` - Full pool configuration, validated against the usual bounds
+    pub fn initialize_pool_full(ctx: Context<InitializePool>, config: PoolConfig) -> Result<()> {
+        instructions::initialize_pool::handler_initialize_pool_full(ctx, config)
+    }
+
     // =========================================================================
     // User Operations
     // =========================================================================
@@ -88,6 +102,118 @@ pub mod vultr {
         instructions::withdraw::handler_withdraw(ctx, shares_to_burn, min_amount_out)
     }
 
+    /// Withdraw by burning shares, without a Depositor account
+    ///
+    /// Lets anyone holding share tokens (e.g., received via transfer) redeem
+    /// them at the current share price. Depositor statistics are not updated.
+    ///
+    /// # Arguments
+    /// * `shares_to_burn` - Number of share tokens to burn
+    /// * `min_amount_out` - Minimum tokens to receive (slippage protection, 0 to skip)
+    pub fn withdraw_no_record(
+        ctx: Context<WithdrawNoRecord>,
+        shares_to_burn: u64,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        instructions::withdraw::handler_withdraw_no_record(ctx, shares_to_burn, min_amount_out)
+    }
+
+    /// Reset the caller's cumulative depositor stats after a full exit
+    ///
+    /// # Requirements
+    /// * Caller must own the depositor account
+    /// * Caller's share balance must be zero
+    pub fn reset_depositor_stats(ctx: Context<ResetDepositorStats>) -> Result<()> {
+        instructions::reset_depositor_stats::handler_reset_depositor_stats(ctx)
+    }
+
+    /// Close the caller's depositor account after a full exit, returning its rent
+    ///
+    /// # Requirements
+    /// * Caller must own the depositor account
+    /// * Caller's share balance must be zero
+    pub fn close_depositor(ctx: Context<CloseDepositor>) -> Result<()> {
+        instructions::close_depositor::handler_close_depositor(ctx)
+    }
+
+    // =========================================================================
+    // Views (read-only, permissionless)
+    // =========================================================================
+
+    /// Get any depositor's position (no signature required)
+    ///
+    /// # Returns
+    /// * `DepositorPosition` with cumulative stats, the current value of the
+    ///   owner's shares at the live share price, and realized PnL
+    pub fn get_depositor_position(ctx: Context<GetDepositorPosition>) -> Result<DepositorPosition> {
+        instructions::views::handler_get_depositor_position(ctx)
+    }
+
+    /// Get any depositor's realized plus unrealized PnL (no signature required)
+    ///
+    /// Uses the same accounts as get_depositor_position.
+    ///
+    /// # Returns
+    /// * `DepositorPnl` with realized PnL, the current value of the owner's
+    ///   shares and their signed sum
+    pub fn get_depositor_pnl(ctx: Context<GetDepositorPosition>) -> Result<DepositorPnl> {
+        instructions::views::handler_get_depositor_pnl(ctx)
+    }
+
+    /// Get any depositor's recent withdrawals (no signature required)
+    ///
+    /// # Returns
+    /// * `WithdrawalHistory` with up to the last 8 withdrawals, oldest first
+    pub fn get_withdrawal_history(ctx: Context<GetWithdrawalHistory>) -> Result<WithdrawalHistory> {
+        instructions::views::handler_get_withdrawal_history(ctx)
+    }
+
+    /// Quote a deposit at the current share price
+    ///
+    /// # Arguments
+    /// * `amount` - Gross deposit amount, before the deposit fee
+    ///
+    /// # Returns
+    /// * `DepositQuote` with shares, fee, net amount and share price (1e6)
+    pub fn quote_deposit(ctx: Context<QuoteDeposit>, amount: u64) -> Result<DepositQuote> {
+        instructions::views::handler_quote_deposit(ctx, amount)
+    }
+
+    /// Quote a withdrawal at the current share price
+    ///
+    /// # Arguments
+    /// * `shares_to_burn` - Number of share tokens to redeem
+    ///
+    /// # Returns
+    /// * `WithdrawQuote` with the payout, vault liquidity and share price (1e6)
+    pub fn quote_withdraw(ctx: Context<QuoteWithdraw>, shares_to_burn: u64) -> Result<WithdrawQuote> {
+        instructions::views::handler_quote_withdraw(ctx, shares_to_burn)
+    }
+
+    /// Preview how a hypothetical profit would be split
+    ///
+    /// # Arguments
+    /// * `profit` - Hypothetical profit amount (in deposit token base units)
+    ///
+    /// # Returns
+    /// * `FeeDistribution` with depositor, staking and treasury slices that
+    ///   sum exactly to `profit` (treasury absorbs rounding dust)
+    pub fn preview_fee_distribution(
+        ctx: Context<PreviewFeeDistribution>,
+        profit: u64,
+    ) -> Result<FeeDistribution> {
+        instructions::views::handler_preview_fee_distribution(ctx, profit)
+    }
+
+    /// Report share price, TVL, profit and cap utilization in one call
+    ///
+    /// # Returns
+    /// * `PoolStats` with share price (1e6), totals, utilization (bps) and
+    ///   pause state
+    pub fn get_pool_stats(ctx: Context<GetPoolStats>) -> Result<PoolStats> {
+        instructions::views::handler_get_pool_stats(ctx)
+    }
+
     // =========================================================================
     // Bot Operations (Team's bot only)
     // =========================================================================
@@ -99,13 +225,18 @@ pub mod vultr {
     ///
     /// # Arguments
     /// * `profit_amount` - Total profit from liquidation (in deposit token base units)
+    /// * `expected_nonce` - Current pool.nonce; rejected with StaleNonce on mismatch
     ///
     /// # Fee Distribution
     /// * 80% to vault (increases share price for depositors)
     /// * 15% to staking_rewards_vault (for VLTR token stakers)
     /// * 5% to treasury (protocol revenue)
-    pub fn record_profit(ctx: Context<RecordProfit>, profit_amount: u64) -> Result<()> {
-        instructions::record_profit::handler_record_profit(ctx, profit_amount)
+    pub fn record_profit(
+        ctx: Context<RecordProfit>,
+        profit_amount: u64,
+        expected_nonce: u64,
+    ) -> Result<()> {
+        instructions::record_profit::handler_record_profit(ctx, profit_amount, expected_nonce)
     }
 
     // =========================================================================
@@ -125,6 +256,18 @@ pub mod vultr {
         instructions::admin::handler_pause_pool(ctx, paused)
     }
 
+    /// Pause individual operations (admin only)
+    ///
+    /// # Arguments
+    /// * `pause_flags` - Bitmask of PAUSE_DEPOSITS (1), PAUSE_WITHDRAWALS (2)
+    ///   and PAUSE_LIQUIDATIONS (4); replaces the current flags, 0 clears all
+    ///
+    /// pause_pool still stops everything at once. Setting PAUSE_WITHDRAWALS
+    /// starts the emergency withdrawal clock; clearing it stops the clock.
+    pub fn set_pause_flags(ctx: Context<PausePool>, pause_flags: u8) -> Result<()> {
+        instructions::admin::handler_set_pause_flags(ctx, pause_flags)
+    }
+
     /// Update fee configuration (admin only)
     ///
     /// # Arguments
@@ -160,7 +303,7 @@ pub mod vultr {
         instructions::admin::handler_update_bot_wallet(ctx)
     }
 
-    /// Update maximum pool size cap (admin only)
+    /// Lower maximum pool size cap immediately (admin only)
     ///
     /// # Arguments
     /// * `new_cap` - New maximum pool size in base units (e.g., USDC with 6 decimals)
@@ -168,11 +311,11 @@ pub mod vultr {
     /// # Purpose
     /// Allows admin to control pool growth for optimal capital efficiency:
     /// - Start with lower cap (500K USDC) for high APY at launch
-    /// - Gradually raise cap as liquidation volume grows
+    /// - Gradually raise cap as liquidation volume grows (via propose_pool_cap)
     /// - Ensure pool size matches available liquidation opportunities
     ///
     /// # Constraints
-    /// - Cannot exceed global MAX_POOL_SIZE (1B USDC)
+    /// - Must be below the current cap; raises need the 24h timelock
     /// - Cannot reduce below current total_deposits
     pub fn update_pool_cap(
         ctx: Context<UpdatePoolCap>,
@@ -181,10 +324,22 @@ pub mod vultr {
         instructions::update_pool_cap::handler_update_pool_cap(ctx, new_cap)
     }
 
-    /// Transfer admin rights to a new address (admin only)
-    /// DEPRECATED: Use propose_admin_transfer + finalize_admin_transfer instead
-    pub fn transfer_admin(ctx: Context<TransferAdmin>) -> Result<()> {
-        instructions::admin::handler_transfer_admin(ctx)
+    /// Propose raising the pool cap (24-hour timelock)
+    ///
+    /// # Arguments
+    /// * `new_cap` - New maximum pool size, above the current cap and at most MAX_POOL_SIZE
+    pub fn propose_pool_cap(ctx: Context<UpdatePoolCap>, new_cap: u64) -> Result<()> {
+        instructions::update_pool_cap::handler_propose_pool_cap(ctx, new_cap)
+    }
+
+    /// Finalize a pool cap increase after 24-hour timelock
+    pub fn finalize_pool_cap(ctx: Context<UpdatePoolCap>) -> Result<()> {
+        instructions::update_pool_cap::handler_finalize_pool_cap(ctx)
+    }
+
+    /// Cancel a pending pool cap increase
+    pub fn cancel_pool_cap(ctx: Context<UpdatePoolCap>) -> Result<()> {
+        instructions::update_pool_cap::handler_cancel_pool_cap(ctx)
     }
 
     // =========================================================================
@@ -198,12 +353,13 @@ pub mod vultr {
     }
 
     /// Finalize an admin transfer after 24-hour timelock
+    /// Must be signed by the pending admin (claim-based transfer)
     pub fn finalize_admin_transfer(ctx: Context<FinalizeAdminTransfer>) -> Result<()> {
         instructions::admin::handler_finalize_admin_transfer(ctx)
     }
 
     /// Cancel a pending admin transfer
-    pub fn cancel_admin_transfer(ctx: Context<FinalizeAdminTransfer>) -> Result<()> {
+    pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
         instructions::admin::handler_cancel_admin_transfer(ctx)
     }
 
@@ -242,19 +398,136 @@ pub mod vultr {
         instructions::admin::handler_cancel_fees(ctx)
     }
 
+    /// Propose a deposit fee update (24-hour timelock)
+    ///
+    /// # Arguments
+    /// * `deposit_fee_bps` - Entry fee sent to the treasury (max 100 = 1%)
+    pub fn propose_deposit_fee(ctx: Context<ProposeFees>, deposit_fee_bps: u16) -> Result<()> {
+        instructions::admin::handler_propose_deposit_fee(ctx, deposit_fee_bps)
+    }
+
+    /// Finalize a deposit fee update after 24-hour timelock
+    pub fn finalize_deposit_fee(ctx: Context<FinalizeFees>) -> Result<()> {
+        instructions::admin::handler_finalize_deposit_fee(ctx)
+    }
+
+    /// Cancel a pending deposit fee update
+    pub fn cancel_deposit_fee(ctx: Context<FinalizeFees>) -> Result<()> {
+        instructions::admin::handler_cancel_deposit_fee(ctx)
+    }
+
+    /// Propose a withdrawal fee update (24-hour timelock)
+    ///
+    /// # Arguments
+    /// * `withdrawal_fee_bps` - Exit fee kept in the vault (max 100 = 1%)
+    pub fn propose_withdrawal_fee(ctx: Context<ProposeFees>, withdrawal_fee_bps: u16) -> Result<()> {
+        instructions::admin::handler_propose_withdrawal_fee(ctx, withdrawal_fee_bps)
+    }
+
+    /// Finalize a withdrawal fee update after 24-hour timelock
+    pub fn finalize_withdrawal_fee(ctx: Context<FinalizeFees>) -> Result<()> {
+        instructions::admin::handler_finalize_withdrawal_fee(ctx)
+    }
+
+    /// Cancel a pending withdrawal fee update
+    pub fn cancel_withdrawal_fee(ctx: Context<FinalizeFees>) -> Result<()> {
+        instructions::admin::handler_cancel_withdrawal_fee(ctx)
+    }
+
+    /// Set the share price sanity band checked by deposits and withdrawals
+    ///
+    /// # Arguments
+    /// * `min_share_price` - Lower bound, SHARE_PRICE_PRECISION scale (0 = none)
+    /// * `max_share_price` - Upper bound, SHARE_PRICE_PRECISION scale (0 = none)
+    pub fn set_share_price_band(
+        ctx: Context<SetSharePriceBand>,
+        min_share_price: u64,
+        max_share_price: u64,
+    ) -> Result<()> {
+        instructions::admin::handler_set_share_price_band(ctx, min_share_price, max_share_price)
+    }
+
+    /// Set the per-user cumulative deposit cap
+    ///
+    /// # Arguments
+    /// * `max_deposit_per_user` - Cap on a depositor's total_deposited (0 = unlimited)
+    pub fn set_max_deposit_per_user(
+        ctx: Context<SetMaxDepositPerUser>,
+        max_deposit_per_user: u64,
+    ) -> Result<()> {
+        instructions::admin::handler_set_max_deposit_per_user(ctx, max_deposit_per_user)
+    }
+
+    /// Set the minimum profit record_profit will accept
+    ///
+    /// # Arguments
+    /// * `min_profit_amount` - Smallest recordable profit in base units (0 = any non-zero profit)
+    pub fn set_min_profit_amount(
+        ctx: Context<SetMinProfitAmount>,
+        min_profit_amount: u64,
+    ) -> Result<()> {
+        instructions::admin::handler_set_min_profit_amount(ctx, min_profit_amount)
+    }
+
+    // =========================================================================
+    // Guardian & Incident Response
+    // =========================================================================
+
+    /// Propose a new guardian (starts 24-hour timelock)
+    ///
+    /// The guardian is an incident-response key that can revoke the bot
+    /// wallet and co-sign emergency_drain, so it must differ from the admin
+    /// and changes are timelocked.
+    pub fn propose_guardian(ctx: Context<ProposeGuardian>) -> Result<()> {
+        instructions::admin::handler_propose_guardian(ctx)
+    }
+
+    /// Finalize a guardian change after the timelock expires
+    pub fn finalize_guardian(ctx: Context<FinalizeGuardian>) -> Result<()> {
+        instructions::admin::handler_finalize_guardian(ctx)
+    }
+
+    /// Cancel a pending guardian change
+    pub fn cancel_guardian(ctx: Context<FinalizeGuardian>) -> Result<()> {
+        instructions::admin::handler_cancel_guardian(ctx)
+    }
+
+    /// Immediately revoke the bot wallet (admin or guardian)
+    ///
+    /// Sets bot_wallet to Pubkey::default(), disabling record_profit until a
+    /// new bot wallet is proposed and finalized through the 24-hour timelock.
+    pub fn emergency_revoke_bot(ctx: Context<EmergencyRevokeBot>) -> Result<()> {
+        instructions::admin::handler_emergency_revoke_bot(ctx)
+    }
+
+    /// Evacuate the entire vault to a recovery account (admin AND guardian)
+    ///
+    /// Last resort for a worst-case exploit. Permanently disables the pool.
+    ///
+    /// # Arguments
+    /// * `recovery` - Owner of the recovery token account (e.g., a safe multisig)
+    ///
+    /// # Requirements
+    /// * Both admin and guardian must sign
+    /// * Pool must have been paused with pause_pool for at least 14 days, a
+    ///   week after emergency withdrawals open
+    pub fn emergency_drain(ctx: Context<EmergencyDrain>, recovery: Pubkey) -> Result<()> {
+        instructions::emergency_drain::handler_emergency_drain(ctx, recovery)
+    }
+
     // =========================================================================
     // SECURITY FIX-6: Emergency Withdrawal
     // =========================================================================
 
-    /// Emergency withdraw when pool has been paused for > 7 days
+    /// Emergency withdraw when withdrawals have been paused for > 7 days
     ///
     /// This allows users to recover their funds if the admin has abandoned
     /// the protocol or is holding funds hostage.
     ///
     /// # Requirements
-    /// * Pool must be paused
-    /// * Pool must have been paused for at least 7 days
+    /// * Withdrawals must be paused, by pause_pool or PAUSE_WITHDRAWALS
+    /// * Withdrawals must have been paused for at least 7 days
     pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>, shares_to_burn: u64) -> Result<()> {
-        instructions::withdraw::handler_emergency_withdraw(ctx, shares_to_burn)
+        instructions::emergency_withdraw::handler_emergency_withdraw(ctx, shares_to_burn)
     }
 }
@@ -14,6 +14,18 @@
 
 use anchor_lang::prelude::*;
 
+use crate::constants::WITHDRAWAL_HISTORY_LEN;
+
+/// One entry in a depositor's withdrawal history
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WithdrawalRecord {
+    /// Deposit tokens paid out
+    pub amount: u64,
+
+    /// Unix timestamp of the withdrawal
+    pub timestamp: i64,
+}
+
 /// Tracks an individual user's position in a VULTR pool.
 ///
 /// This account is a PDA derived from ["depositor", pool_pubkey, owner_pubkey].
@@ -29,11 +41,13 @@ use anchor_lang::prelude::*;
 /// - deposit_count: 4 bytes
 /// - last_deposit_timestamp: 8 bytes
 /// - last_withdrawal_timestamp: 8 bytes
+/// - first_deposit_timestamp: 8 bytes
+/// - withdrawals_recorded: 4 bytes
+/// - withdrawal_history: 8 * 16 = 128 bytes
 /// - bump: 1 byte
-/// - _padding: 3 bytes
-/// Total: 8 + 120 = 128 bytes
+/// Total: 8 + 249 = 257 bytes
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Depositor {
     // =========================================================================
     // Account References
@@ -80,6 +94,22 @@ pub struct Depositor {
     /// Unix timestamp of the user's most recent withdrawal
     pub last_withdrawal_timestamp: i64,
 
+    /// Unix timestamp of the user's very first deposit ("member since")
+    /// Set once and never reset, unlike last_deposit_timestamp
+    pub first_deposit_timestamp: i64,
+
+    // =========================================================================
+    // Withdrawal History
+    // =========================================================================
+
+    /// Total withdrawals ever appended to withdrawal_history
+    /// Never reset, so it doubles as the ring buffer's write cursor
+    pub withdrawals_recorded: u32,
+
+    /// Ring buffer of the most recent withdrawals
+    /// Slot `withdrawals_recorded % WITHDRAWAL_HISTORY_LEN` is written next
+    pub withdrawal_history: [WithdrawalRecord; WITHDRAWAL_HISTORY_LEN],
+
     // =========================================================================
     // PDA Bump
     // =========================================================================
@@ -96,6 +126,7 @@ impl Depositor {
     /// - total_deposited: Adds the deposit amount
     /// - deposit_count: Increments by 1
     /// - last_deposit_timestamp: Sets to current time
+    /// - first_deposit_timestamp: Sets to current time on the first deposit only
     ///
     /// # Arguments
     /// * `deposit_amount` - Amount of deposit tokens being deposited
@@ -124,6 +155,10 @@ impl Depositor {
 
         self.last_deposit_timestamp = timestamp;
 
+        if self.first_deposit_timestamp == 0 {
+            self.first_deposit_timestamp = timestamp;
+        }
+
         Ok(())
     }
 
@@ -132,6 +167,7 @@ impl Depositor {
     /// Updates:
     /// - total_withdrawn: Adds the withdrawal amount
     /// - last_withdrawal_timestamp: Sets to current time
+    /// - withdrawal_history: Appends (amount, timestamp), evicting the oldest
     ///
     /// # Arguments
     /// * `withdrawal_amount` - Amount of deposit tokens being withdrawn
@@ -144,9 +180,41 @@ impl Depositor {
 
         self.last_withdrawal_timestamp = timestamp;
 
+        let slot = self.withdrawals_recorded as usize % WITHDRAWAL_HISTORY_LEN;
+        self.withdrawal_history[slot] = WithdrawalRecord {
+            amount: withdrawal_amount,
+            timestamp,
+        };
+        self.withdrawals_recorded = self.withdrawals_recorded.wrapping_add(1);
+
         Ok(())
     }
 
+    /// The retained withdrawal history, oldest first
+    pub fn recent_withdrawals(&self) -> Vec<WithdrawalRecord> {
+        let retained = (self.withdrawals_recorded as usize).min(WITHDRAWAL_HISTORY_LEN);
+        let next = self.withdrawals_recorded as usize % WITHDRAWAL_HISTORY_LEN;
+
+        let oldest = (next + WITHDRAWAL_HISTORY_LEN - retained) % WITHDRAWAL_HISTORY_LEN;
+
+        (0..retained)
+            .map(|i| self.withdrawal_history[(oldest + i) % WITHDRAWAL_HISTORY_LEN])
+            .collect()
+    }
+
+    /// Reset cumulative statistics after the user has fully exited
+    ///
+    /// Zeroes shares_minted, total_deposited, total_withdrawn and
+    /// deposit_count so a returning user starts from a clean slate.
+    /// Timestamps (including first_deposit_timestamp) and withdrawal history
+    /// are kept as a record of past activity.
+    pub fn reset_stats(&mut self) {
+        self.shares_minted = 0;
+        self.total_deposited = 0;
+        self.total_withdrawn = 0;
+        self.deposit_count = 0;
+    }
+
     /// Calculate the user's realized profit/loss
     ///
     /// This only considers completed transactions:
@@ -161,6 +229,21 @@ impl Depositor {
         (self.total_withdrawn as i64) - (self.total_deposited as i64)
     }
 
+    /// Calculate the user's total profit/loss, realized plus unrealized
+    ///
+    /// profit = total_withdrawn + current_value - total_deposited
+    ///
+    /// # Arguments
+    /// * `current_value` - Value of the user's current shares at the live
+    ///   share price (see Pool::calculate_withdrawal_amount)
+    ///
+    /// Returns: Positive for profit, negative for loss (as i64)
+    pub fn total_pnl(&self, current_value: u64) -> Result<i64> {
+        self.realized_pnl()
+            .checked_add(current_value as i64)
+            .ok_or_else(|| error!(crate::error::VultrError::MathOverflow))
+    }
+
     /// Calculate time since the user's most recent deposit
     ///
     /// # Arguments
@@ -174,6 +257,19 @@ impl Depositor {
         current_timestamp.saturating_sub(self.last_deposit_timestamp)
     }
 
+    /// Calculate time since the user's first ever deposit
+    ///
+    /// # Arguments
+    /// * `current_timestamp` - Current unix timestamp
+    ///
+    /// Returns: Duration in seconds since first deposit, or 0 if never deposited
+    pub fn time_since_first_deposit(&self, current_timestamp: i64) -> i64 {
+        if self.first_deposit_timestamp == 0 {
+            return 0;
+        }
+        current_timestamp.saturating_sub(self.first_deposit_timestamp)
+    }
+
     /// Calculate time since the user's most recent withdrawal
     ///
     /// # Arguments
@@ -187,3 +283,60 @@ impl Depositor {
         current_timestamp.saturating_sub(self.last_withdrawal_timestamp)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_deposit_timestamp_survives_later_deposits_and_resets() {
+        let mut depositor = Depositor::default();
+        assert_eq!(depositor.time_since_first_deposit(1_000), 0);
+
+        depositor.record_deposit(1_000_000, 1_000_000, 100).unwrap();
+        depositor.record_deposit(1_000_000, 1_000_000, 500).unwrap();
+        assert_eq!(depositor.first_deposit_timestamp, 100);
+        assert_eq!(depositor.last_deposit_timestamp, 500);
+        assert_eq!(depositor.time_since_first_deposit(1_000), 900);
+
+        depositor.reset_stats();
+        depositor.record_deposit(1_000_000, 1_000_000, 800).unwrap();
+        assert_eq!(depositor.first_deposit_timestamp, 100);
+    }
+
+    #[test]
+    fn withdrawal_history_keeps_most_recent_in_order() {
+        let mut depositor = Depositor::default();
+        assert!(depositor.recent_withdrawals().is_empty());
+
+        for i in 1..=3u64 {
+            depositor.record_withdrawal(i * 100, i as i64).unwrap();
+        }
+        let amounts: Vec<u64> = depositor.recent_withdrawals().iter().map(|r| r.amount).collect();
+        assert_eq!(amounts, vec![100, 200, 300]);
+
+        // Wrap past the buffer: only the last WITHDRAWAL_HISTORY_LEN survive
+        for i in 4..=11u64 {
+            depositor.record_withdrawal(i * 100, i as i64).unwrap();
+        }
+        let history = depositor.recent_withdrawals();
+        assert_eq!(history.len(), WITHDRAWAL_HISTORY_LEN);
+        assert_eq!(history.first(), Some(&WithdrawalRecord { amount: 400, timestamp: 4 }));
+        assert_eq!(history.last(), Some(&WithdrawalRecord { amount: 1_100, timestamp: 11 }));
+        assert!(history.windows(2).all(|w| w[0].timestamp < w[1].timestamp));
+        assert_eq!(depositor.withdrawals_recorded, 11);
+    }
+
+    #[test]
+    fn total_pnl_adds_current_value_to_realized() {
+        let mut depositor = Depositor::default();
+        depositor.record_deposit(1_000_000, 1_000_000, 1).unwrap();
+        depositor.record_withdrawal(400_000, 2).unwrap();
+        assert_eq!(depositor.realized_pnl(), -600_000);
+
+        // Remaining shares worth more than the cost basis left in the pool
+        assert_eq!(depositor.total_pnl(650_000).unwrap(), 50_000);
+        // Fully exited at a loss
+        assert_eq!(depositor.total_pnl(0).unwrap(), -600_000);
+    }
+}
@@ -12,12 +12,14 @@
 
 use anchor_lang::prelude::*;
 
+use crate::constants::*;
+
 /// The main Pool account that stores all protocol state.
 ///
 /// This account is created once per deposit token (e.g., one pool for USDC).
 /// It's a PDA derived from ["pool", deposit_mint_pubkey].
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Pool {
     // =========================================================================
     // Authority & Identification
@@ -31,6 +33,11 @@ pub struct Pool {
     /// This is NOT an external operator - this is the team's bot wallet
     pub bot_wallet: Pubkey,
 
+    /// Optional incident-response key (Pubkey::default() if unset)
+    /// Can revoke the bot wallet and co-signs emergency_drain with the admin;
+    /// must differ from the admin and changes go through a 24h timelock
+    pub guardian: Pubkey,
+
     /// The SPL token mint for deposits (e.g., USDC)
     /// Users deposit this token to receive shares
     pub deposit_mint: Pubkey,
@@ -70,6 +77,15 @@ pub struct Pool {
     /// Total number of liquidations executed
     pub total_liquidations: u64,
 
+    /// Monotonic counter incremented on each record_profit
+    /// The bot must pass the current value, so duplicate or out-of-order
+    /// submissions are rejected
+    pub nonce: u64,
+
+    /// Total deposit tokens paid out through emergency_withdraw (cumulative)
+    /// Tracked separately so incident outflows stand out from normal exits
+    pub emergency_withdrawn_total: u64,
+
     // =========================================================================
     // Fee Configuration (in basis points, 1 BPS = 0.01%)
     // Must sum to 10000 (100%)
@@ -88,6 +104,14 @@ pub struct Pool {
     /// Default: 500 BPS (5%)
     pub treasury_fee_bps: u16,
 
+    /// Entry fee charged on each deposit, sent to the treasury
+    /// Not part of the 10000 BPS profit split. Default: 0, max MAX_DEPOSIT_FEE_BPS
+    pub deposit_fee_bps: u16,
+
+    /// Exit fee charged on each withdrawal, left in the vault for the
+    /// remaining depositors. Default: 0, max MAX_WITHDRAWAL_FEE_BPS
+    pub withdrawal_fee_bps: u16,
+
     // =========================================================================
     // Pool Status & Configuration
     // =========================================================================
@@ -96,19 +120,52 @@ pub struct Pool {
     /// When true, no deposits, withdrawals, or profit recording allowed
     pub is_paused: bool,
 
+    /// Granular pause bits (PAUSE_DEPOSITS, PAUSE_WITHDRAWALS,
+    /// PAUSE_LIQUIDATIONS), applied on top of is_paused
+    pub pause_flags: u8,
+
     /// Maximum total deposits allowed in this pool (in base units)
     /// Default: 500,000 USDC (500_000_000_000 with 6 decimals)
     pub max_pool_size: u64,
 
+    /// Maximum cumulative deposits per depositor (Depositor.total_deposited)
+    /// 0 = unlimited (default)
+    pub max_deposit_per_user: u64,
+
+    /// Smallest profit record_profit will accept (in base units)
+    /// 0 = any non-zero profit (default)
+    pub min_profit_amount: u64,
+
+    /// Terminal flag set by emergency_drain
+    /// Once set the pool stays paused forever and the vault is empty
+    pub is_disabled: bool,
+
+    // =========================================================================
+    // Share Price Sanity Band
+    // =========================================================================
+
+    /// Lowest acceptable share price (SHARE_PRICE_PRECISION scale)
+    /// Deposits and withdrawals revert below it. 0 = no lower bound
+    pub min_share_price: u64,
+
+    /// Highest acceptable share price (SHARE_PRICE_PRECISION scale)
+    /// Deposits and withdrawals revert above it. 0 = no upper bound
+    pub max_share_price: u64,
+
     // =========================================================================
     // SECURITY: Timelock Fields (FIX-4, FIX-5, FIX-6, FIX-7)
     // All sensitive admin operations require a 24-hour delay
     // =========================================================================
 
-    /// Timestamp when pool was paused (for emergency withdrawal after 7 days)
-    /// 0 if not paused. Set by pause_pool instruction.
+    /// Timestamp when withdrawals were paused (for emergency withdrawal after
+    /// 7 days). 0 if not paused. Set by pause_pool and set_pause_flags.
     pub pause_timestamp: i64,
 
+    /// Timestamp when pause_pool paused the whole pool (for emergency_drain
+    /// after 14 days). 0 if not paused. Unlike pause_timestamp, an earlier
+    /// PAUSE_WITHDRAWALS flag does not move it back.
+    pub full_pause_timestamp: i64,
+
     /// Pending new admin address (requires 24h timelock)
     /// None (Pubkey::default()) if no pending change
     pub pending_admin: Pubkey,
@@ -132,6 +189,35 @@ pub struct Pool {
     /// Timestamp when fee change was proposed
     pub fee_change_timestamp: i64,
 
+    /// Pending deposit fee (requires 24h timelock)
+    /// Only meaningful while deposit_fee_change_timestamp != 0, since 0 bps
+    /// is a valid target
+    pub pending_deposit_fee_bps: u16,
+
+    /// Timestamp when deposit fee change was proposed (0 if none pending)
+    pub deposit_fee_change_timestamp: i64,
+
+    /// Pending withdrawal fee (requires 24h timelock)
+    /// Only meaningful while withdrawal_fee_change_timestamp != 0
+    pub pending_withdrawal_fee_bps: u16,
+
+    /// Timestamp when withdrawal fee change was proposed (0 if none pending)
+    pub withdrawal_fee_change_timestamp: i64,
+
+    /// Pending pool cap increase (requires 24h timelock)
+    /// 0 if no pending change
+    pub pending_max_pool_size: u64,
+
+    /// Timestamp when pool cap increase was proposed (0 if none pending)
+    pub pool_cap_change_timestamp: i64,
+
+    /// Pending guardian (requires 24h timelock)
+    /// Pubkey::default() if no pending change
+    pub pending_guardian: Pubkey,
+
+    /// Timestamp when guardian change was proposed (0 if none pending)
+    pub guardian_change_timestamp: i64,
+
     /// Whether admin is a multisig (informational, for frontends)
     pub admin_is_multisig: bool,
 
@@ -162,7 +248,10 @@ impl Pool {
     ///
     /// Formula:
     /// - If pool is empty (first deposit): shares = deposit_amount
-    /// - Otherwise: shares = (deposit_amount * total_shares) / total_value
+    /// - Otherwise: shares = floor(deposit_amount * total_shares / total_value)
+    ///
+    /// Rounds DOWN so the depositor never receives more shares than they paid
+    /// for; any rounding remainder stays with existing shareholders.
     pub fn calculate_shares_to_mint(&self, deposit_amount: u64) -> Result<u64> {
         if self.total_shares == 0 {
             // First deposit: 1:1 ratio
@@ -174,34 +263,198 @@ impl Pool {
                 return Ok(deposit_amount);
             }
 
-            // Use u128 for intermediate calculation to prevent overflow
-            let shares = (deposit_amount as u128)
-                .checked_mul(self.total_shares as u128)
-                .ok_or(error!(crate::error::VultrError::MathOverflow))?
-                .checked_div(total_value as u128)
-                .ok_or(error!(crate::error::VultrError::DivisionByZero))?;
+            mul_div_floor(deposit_amount, self.total_shares, total_value)
+        }
+    }
 
-            Ok(shares as u64)
+    /// Quote a deposit of `amount` gross deposit tokens against current state
+    ///
+    /// Runs every pool-side check handler_deposit makes, so the quote_deposit
+    /// view and a real deposit cannot drift apart. Minimums and the
+    /// first-deposit guard use the gross amount; shares, the pool cap and
+    /// pool accounting use the net amount that reaches the vault. The deposit
+    /// fee rounds UP, since the user pays it.
+    ///
+    /// Returns: (deposit_fee, net_amount, shares_to_mint)
+    pub fn quote_deposit(&self, amount: u64) -> Result<(u64, u64, u64)> {
+        use crate::error::VultrError;
+
+        require!(amount > 0, VultrError::InvalidAmount);
+        require!(amount >= MIN_DEPOSIT_AMOUNT, VultrError::BelowMinimumDeposit);
+        require!(amount <= MAX_DEPOSIT_AMOUNT, VultrError::ExceedsMaxDeposit);
+
+        // If this is the first deposit (pool is empty), require larger minimum
+        // This prevents the share price inflation attack where:
+        // 1. Attacker deposits 1 token, gets 1 share
+        // 2. Attacker transfers many tokens directly to vault (not through deposit)
+        // 3. Share price becomes inflated, next depositor gets ~0 shares
+        if self.total_shares == 0 {
+            require!(amount >= MIN_FIRST_DEPOSIT, VultrError::BelowMinimumDeposit);
         }
+
+        let deposit_fee = mul_div_ceil(amount, self.deposit_fee_bps as u64, BPS_DENOMINATOR as u64)?;
+        let net_amount = amount
+            .checked_sub(deposit_fee)
+            .ok_or(VultrError::MathUnderflow)?;
+
+        // Refuse to price against accounting that has left the sanity band
+        self.check_share_price_sanity()?;
+
+        let shares_to_mint = self.calculate_shares_to_mint(net_amount)?;
+
+        // Ensure we're minting at least MIN_SHARES_MINTED (prevent rounding attacks)
+        // where a manipulated share price rounds the deposit down to ~0 shares
+        require!(shares_to_mint >= MIN_SHARES_MINTED, VultrError::ShareAmountZero);
+
+        let new_total = self
+            .total_deposits
+            .checked_add(net_amount)
+            .ok_or(VultrError::MathOverflow)?;
+        require!(new_total <= self.max_pool_size, VultrError::ExceedsMaxPoolSize);
+
+        self.check_share_supply(shares_to_mint)?;
+
+        Ok((deposit_fee, net_amount, shares_to_mint))
+    }
+
+    /// Re-price a deposit on the tokens the vault actually received
+    ///
+    /// Used when a Token-2022 transfer fee leaves the vault short of the
+    /// quoted net amount. Re-applies the MIN_SHARES_MINTED floor and share
+    /// supply cap to the smaller amount; the pool cap needs no re-check since
+    /// `received` is below the amount quote_deposit already admitted.
+    ///
+    /// Returns: shares_to_mint
+    pub fn quote_received_deposit(&self, received: u64) -> Result<u64> {
+        use crate::error::VultrError;
+
+        let shares_to_mint = self.calculate_shares_to_mint(received)?;
+        require!(shares_to_mint >= MIN_SHARES_MINTED, VultrError::ShareAmountZero);
+        self.check_share_supply(shares_to_mint)?;
+
+        Ok(shares_to_mint)
+    }
+
+    /// Ensure minting `shares_to_mint` keeps total_shares within MAX_TOTAL_SHARES
+    ///
+    /// Summed in u128 so the guard trips with ExceedsMaxPoolSize before any
+    /// u64 overflow can occur.
+    pub fn check_share_supply(&self, shares_to_mint: u64) -> Result<()> {
+        let new_total_shares = (self.total_shares as u128) + (shares_to_mint as u128);
+
+        if new_total_shares > MAX_TOTAL_SHARES as u128 {
+            return Err(error!(crate::error::VultrError::ExceedsMaxPoolSize));
+        }
+
+        Ok(())
     }
 
     /// Calculate how many deposit tokens to return for burning shares
     ///
-    /// Formula: withdrawal_amount = (shares_to_burn * total_value) / total_shares
+    /// Formula: withdrawal_amount = floor(shares_to_burn * total_value / total_shares)
+    ///
+    /// Rounds DOWN so the withdrawer never receives more than their shares
+    /// are worth; any rounding remainder stays in the pool.
     pub fn calculate_withdrawal_amount(&self, shares_to_burn: u64) -> Result<u64> {
         if self.total_shares == 0 {
             return Err(error!(crate::error::VultrError::DivisionByZero));
         }
 
-        let total_value = self.total_value();
+        mul_div_floor(shares_to_burn, self.total_value(), self.total_shares)
+    }
 
-        let amount = (shares_to_burn as u128)
-            .checked_mul(total_value as u128)
-            .ok_or(error!(crate::error::VultrError::MathOverflow))?
-            .checked_div(self.total_shares as u128)
-            .ok_or(error!(crate::error::VultrError::DivisionByZero))?;
+    /// Whether deposits are stopped, by pause_pool or PAUSE_DEPOSITS
+    pub fn deposits_paused(&self) -> bool {
+        self.is_paused || self.pause_flags & PAUSE_DEPOSITS != 0
+    }
+
+    /// Whether withdrawals are stopped, by pause_pool or PAUSE_WITHDRAWALS
+    pub fn withdrawals_paused(&self) -> bool {
+        self.is_paused || self.pause_flags & PAUSE_WITHDRAWALS != 0
+    }
+
+    /// Whether profit recording is stopped, by pause_pool or PAUSE_LIQUIDATIONS
+    pub fn liquidations_paused(&self) -> bool {
+        self.is_paused || self.pause_flags & PAUSE_LIQUIDATIONS != 0
+    }
+
+    /// Current share price: total_value / total_shares, scaled by
+    /// SHARE_PRICE_PRECISION (1.0 while the pool has no shares)
+    pub fn share_price(&self) -> Result<u64> {
+        if self.total_shares == 0 {
+            return Ok(SHARE_PRICE_PRECISION);
+        }
+
+        mul_div_floor(self.total_value(), SHARE_PRICE_PRECISION, self.total_shares)
+    }
+
+    /// Share of the pool cap in use, in basis points
+    /// (total_deposits * 10000 / max_pool_size, 0 when no cap is set)
+    pub fn utilization_bps(&self) -> Result<u16> {
+        if self.max_pool_size == 0 {
+            return Ok(0);
+        }
+
+        let utilization = mul_div_floor(self.total_deposits, BPS_DENOMINATOR as u64, self.max_pool_size)?;
+        Ok(utilization.min(u16::MAX as u64) as u16)
+    }
+
+    /// Defense-in-depth check that the share price sits inside the admin's
+    /// configured band
+    ///
+    /// A price outside the band means total_deposits and total_shares have
+    /// drifted apart (accounting bug or manipulation), so deposits and
+    /// withdrawals refuse to price against it. Empty pools and pools with no
+    /// band configured always pass.
+    pub fn check_share_price_sanity(&self) -> Result<()> {
+        if self.total_shares == 0 || (self.min_share_price == 0 && self.max_share_price == 0) {
+            return Ok(());
+        }
+
+        let price = self.share_price()?;
+
+        if (self.min_share_price != 0 && price < self.min_share_price)
+            || (self.max_share_price != 0 && price > self.max_share_price)
+        {
+            return Err(error!(crate::error::VultrError::SharePriceSanityFailed));
+        }
+
+        Ok(())
+    }
+
+    /// Quote burning `shares_to_burn` shares against current state
+    ///
+    /// Runs the pool-side checks handler_withdraw makes (share supply and
+    /// sanity band) so the quote_withdraw view matches a real withdrawal.
+    /// Vault liquidity is left to the caller.
+    ///
+    /// The withdrawal fee rounds UP, since the user pays it, and is waived
+    /// when the last shares are burned, since there would be no remaining
+    /// depositors to keep it for.
+    ///
+    /// Returns: (amount_out, withdrawal_fee)
+    pub fn quote_withdrawal(&self, shares_to_burn: u64) -> Result<(u64, u64)> {
+        use crate::error::VultrError;
+
+        require!(shares_to_burn > 0, VultrError::InvalidAmount);
+        require!(self.total_shares >= shares_to_burn, VultrError::InsufficientShares);
+
+        // Refuse to price against accounting that has left the sanity band
+        self.check_share_price_sanity()?;
+
+        let gross_amount = self.calculate_withdrawal_amount(shares_to_burn)?;
 
-        Ok(amount as u64)
+        let withdrawal_fee = if shares_to_burn == self.total_shares {
+            0
+        } else {
+            mul_div_ceil(gross_amount, self.withdrawal_fee_bps as u64, BPS_DENOMINATOR as u64)?
+        };
+
+        let amount_out = gross_amount
+            .checked_sub(withdrawal_fee)
+            .ok_or(VultrError::MathUnderflow)?;
+
+        Ok((amount_out, withdrawal_fee))
     }
 
     /// Validate that the fee configuration is correct
@@ -226,7 +479,12 @@ impl Pool {
     /// - depositor_share goes to vault (increases share price)
     /// - staking_share goes to staking_rewards_vault
     /// - treasury_share goes to treasury
+    ///
+    /// Re-validates the stored fee configuration first, so a pool whose fee
+    /// fields were somehow corrupted aborts instead of misdistributing.
     pub fn calculate_fee_distribution(&self, profit: u64) -> Result<(u64, u64, u64)> {
+        self.validate_fees()?;
+
         // depositor_share = profit * depositor_fee_bps / 10000 (80%)
         let depositor_share = (profit as u128)
             .checked_mul(self.depositor_fee_bps as u128)
@@ -252,3 +510,470 @@ impl Pool {
         Ok((depositor_share, staking_share, treasury_share))
     }
 }
+
+// =============================================================================
+// Pool Configuration
+// =============================================================================
+
+/// Every configurable pool field, set in one shot by initialize_pool_full
+///
+/// The pool is not live until it exists, so these bypass the timelocks that
+/// guard the same fields afterwards. Default reproduces initialize_pool.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolConfig {
+    /// Profit split; must sum to BPS_DENOMINATOR
+    pub depositor_fee_bps: u16,
+    pub staking_fee_bps: u16,
+    pub treasury_fee_bps: u16,
+
+    /// Entry fee on deposits (max MAX_DEPOSIT_FEE_BPS)
+    pub deposit_fee_bps: u16,
+
+    /// Exit fee on withdrawals (max MAX_WITHDRAWAL_FEE_BPS)
+    pub withdrawal_fee_bps: u16,
+
+    /// TVL cap in deposit token base units (1..=MAX_POOL_SIZE)
+    pub max_pool_size: u64,
+
+    /// Per-user cumulative deposit cap (0 = unlimited)
+    pub max_deposit_per_user: u64,
+
+    /// Smallest profit record_profit accepts (0 = any non-zero profit)
+    pub min_profit_amount: u64,
+
+    /// Share price sanity band (0 disables a bound)
+    pub min_share_price: u64,
+    pub max_share_price: u64,
+
+    /// Guardian allowed to revoke the bot and co-sign emergency_drain
+    /// (Pubkey::default() for none; must differ from the admin)
+    pub guardian: Pubkey,
+
+    /// Informational flag for frontends
+    pub admin_is_multisig: bool,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            depositor_fee_bps: DEPOSITOR_FEE_BPS,
+            staking_fee_bps: STAKING_FEE_BPS,
+            treasury_fee_bps: TREASURY_FEE_BPS,
+            deposit_fee_bps: 0,
+            withdrawal_fee_bps: 0,
+            max_pool_size: DEFAULT_POOL_SIZE,
+            max_deposit_per_user: 0,
+            min_profit_amount: 0,
+            min_share_price: 0,
+            max_share_price: 0,
+            guardian: Pubkey::default(),
+            admin_is_multisig: false,
+        }
+    }
+}
+
+impl PoolConfig {
+    /// Apply the same bounds the post-launch admin instructions enforce
+    pub fn validate(&self) -> Result<()> {
+        use crate::error::VultrError;
+
+        let total_bps = (self.depositor_fee_bps as u32)
+            + (self.staking_fee_bps as u32)
+            + (self.treasury_fee_bps as u32);
+
+        require!(total_bps == BPS_DENOMINATOR as u32, VultrError::InvalidFeeConfig);
+        require!(self.depositor_fee_bps >= 5000, VultrError::FeeExceedsMax);
+        require!(self.staking_fee_bps <= 3000, VultrError::FeeExceedsMax);
+        require!(self.treasury_fee_bps <= 2000, VultrError::FeeExceedsMax);
+        require!(self.deposit_fee_bps <= MAX_DEPOSIT_FEE_BPS, VultrError::FeeExceedsMax);
+        require!(
+            self.withdrawal_fee_bps <= MAX_WITHDRAWAL_FEE_BPS,
+            VultrError::WithdrawalFeeExceedsMax
+        );
+
+        require!(self.max_pool_size > 0, VultrError::InvalidPoolCap);
+        require!(self.max_pool_size <= MAX_POOL_SIZE, VultrError::ExceedsMaxPoolSize);
+
+        require!(
+            self.max_share_price == 0 || self.min_share_price <= self.max_share_price,
+            VultrError::InvalidSharePriceBand
+        );
+
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Rounding Helpers
+// =============================================================================
+// All share math goes through these so the rounding direction is explicit.
+// Pool-favorable rounding means the pool never loses value to rounding:
+// deposits and withdrawals both round DOWN what the user receives.
+// =============================================================================
+
+/// floor(a * b / c), computed in u128
+pub fn mul_div_floor(a: u64, b: u64, c: u64) -> Result<u64> {
+    let result = (a as u128)
+        .checked_mul(b as u128)
+        .ok_or(error!(crate::error::VultrError::MathOverflow))?
+        .checked_div(c as u128)
+        .ok_or(error!(crate::error::VultrError::DivisionByZero))?;
+
+    u64::try_from(result).map_err(|_| error!(crate::error::VultrError::MathOverflow))
+}
+
+/// ceil(a * b / c), computed in u128
+///
+/// Use when computing what a user must PAY (e.g., shares required for an
+/// exact output amount), so rounding still favors the pool.
+pub fn mul_div_ceil(a: u64, b: u64, c: u64) -> Result<u64> {
+    if c == 0 {
+        return Err(error!(crate::error::VultrError::DivisionByZero));
+    }
+
+    let product = (a as u128)
+        .checked_mul(b as u128)
+        .ok_or(error!(crate::error::VultrError::MathOverflow))?;
+    let result = product.div_ceil(c as u128);
+
+    u64::try_from(result).map_err(|_| error!(crate::error::VultrError::MathOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::VultrError;
+
+    fn pool_with_shares(total_shares: u64) -> Pool {
+        Pool {
+            total_deposits: total_shares,
+            total_shares,
+            ..Pool::default()
+        }
+    }
+
+    #[test]
+    fn share_supply_allows_mint_up_to_max() {
+        let pool = pool_with_shares(MAX_TOTAL_SHARES - 1_000);
+        assert!(pool.check_share_supply(1_000).is_ok());
+    }
+
+    #[test]
+    fn share_supply_rejects_mint_past_max() {
+        let pool = pool_with_shares(MAX_TOTAL_SHARES - 1_000);
+        assert_eq!(
+            pool.check_share_supply(1_001).unwrap_err(),
+            error!(VultrError::ExceedsMaxPoolSize)
+        );
+    }
+
+    #[test]
+    fn share_supply_trips_before_u64_overflow() {
+        let pool = pool_with_shares(MAX_TOTAL_SHARES);
+        assert_eq!(
+            pool.check_share_supply(u64::MAX).unwrap_err(),
+            error!(VultrError::ExceedsMaxPoolSize)
+        );
+    }
+
+    #[test]
+    fn fee_distribution_rejects_corrupted_fee_config() {
+        let pool = Pool {
+            depositor_fee_bps: 8000,
+            staking_fee_bps: 1500,
+            treasury_fee_bps: 600, // sums to 10100
+            ..Pool::default()
+        };
+        assert_eq!(
+            pool.calculate_fee_distribution(1_000_000).unwrap_err(),
+            error!(VultrError::InvalidFeeConfig)
+        );
+
+        let pool = Pool {
+            treasury_fee_bps: 500,
+            ..pool
+        };
+        assert_eq!(
+            pool.calculate_fee_distribution(1_000_000).unwrap(),
+            (800_000, 150_000, 50_000)
+        );
+    }
+
+    #[test]
+    fn share_price_sanity_trips_on_corrupted_deposits() {
+        // Band of 0.5 .. 2.0 around a 1:1 pool
+        let pool = Pool {
+            min_share_price: SHARE_PRICE_PRECISION / 2,
+            max_share_price: SHARE_PRICE_PRECISION * 2,
+            ..pool_with_shares(1_000_000_000)
+        };
+        assert!(pool.check_share_price_sanity().is_ok());
+
+        // total_deposits inflated 10x without minting shares
+        let inflated = Pool {
+            total_deposits: 10_000_000_000,
+            ..pool.clone()
+        };
+        assert_eq!(inflated.share_price().unwrap(), SHARE_PRICE_PRECISION * 10);
+        assert_eq!(
+            inflated.check_share_price_sanity().unwrap_err(),
+            error!(VultrError::SharePriceSanityFailed)
+        );
+
+        // total_deposits wiped while shares remain outstanding
+        let drained = Pool {
+            total_deposits: 1,
+            ..pool
+        };
+        assert_eq!(
+            drained.check_share_price_sanity().unwrap_err(),
+            error!(VultrError::SharePriceSanityFailed)
+        );
+    }
+
+    #[test]
+    fn share_price_sanity_disabled_bounds_and_empty_pool_pass() {
+        let unbounded = Pool {
+            total_deposits: u64::MAX,
+            ..pool_with_shares(1)
+        };
+        assert!(unbounded.check_share_price_sanity().is_ok());
+
+        let empty = Pool {
+            min_share_price: SHARE_PRICE_PRECISION,
+            max_share_price: SHARE_PRICE_PRECISION,
+            ..Pool::default()
+        };
+        assert!(empty.check_share_price_sanity().is_ok());
+    }
+
+    #[test]
+    fn pool_config_default_is_valid_and_bad_configs_are_rejected() {
+        assert!(PoolConfig::default().validate().is_ok());
+
+        let cases = [
+            (PoolConfig { treasury_fee_bps: 600, ..PoolConfig::default() }, VultrError::InvalidFeeConfig),
+            (
+                PoolConfig { depositor_fee_bps: 4000, staking_fee_bps: 3000, treasury_fee_bps: 3000, ..PoolConfig::default() },
+                VultrError::FeeExceedsMax,
+            ),
+            (PoolConfig { deposit_fee_bps: MAX_DEPOSIT_FEE_BPS + 1, ..PoolConfig::default() }, VultrError::FeeExceedsMax),
+            (
+                PoolConfig { withdrawal_fee_bps: MAX_WITHDRAWAL_FEE_BPS + 1, ..PoolConfig::default() },
+                VultrError::WithdrawalFeeExceedsMax,
+            ),
+            (PoolConfig { max_pool_size: 0, ..PoolConfig::default() }, VultrError::InvalidPoolCap),
+            (PoolConfig { max_pool_size: MAX_POOL_SIZE + 1, ..PoolConfig::default() }, VultrError::ExceedsMaxPoolSize),
+            (
+                PoolConfig { min_share_price: 2, max_share_price: 1, ..PoolConfig::default() },
+                VultrError::InvalidSharePriceBand,
+            ),
+        ];
+        for (config, expected) in cases {
+            assert_eq!(config.validate().unwrap_err(), error!(expected), "{:?}", config);
+        }
+    }
+
+    #[test]
+    fn quote_deposit_applies_minimums_and_fee() {
+        let empty = Pool {
+            max_pool_size: DEFAULT_POOL_SIZE,
+            ..Pool::default()
+        };
+        assert_eq!(
+            empty.quote_deposit(MIN_FIRST_DEPOSIT - 1).unwrap_err(),
+            error!(VultrError::BelowMinimumDeposit)
+        );
+        assert_eq!(
+            empty.quote_deposit(MIN_FIRST_DEPOSIT).unwrap(),
+            (0, MIN_FIRST_DEPOSIT, MIN_FIRST_DEPOSIT)
+        );
+
+        // 1% fee on a 2:1 pool: fee off the top, shares on the net amount
+        let pool = Pool {
+            total_deposits: 2_000_000_000,
+            total_shares: 1_000_000_000,
+            deposit_fee_bps: 100,
+            ..empty
+        };
+        assert_eq!(
+            pool.quote_deposit(10_000_000).unwrap(),
+            (100_000, 9_900_000, 4_950_000)
+        );
+
+        // A fractional fee rounds up: 0.3% of 10_000_001 is 30_000.003
+        let odd_fee = Pool {
+            deposit_fee_bps: 30,
+            ..pool
+        };
+        assert_eq!(
+            odd_fee.quote_deposit(10_000_001).unwrap(),
+            (30_001, 9_970_000, 4_985_000)
+        );
+
+        let full = Pool {
+            max_pool_size: 2_000_000_000,
+            ..pool
+        };
+        assert_eq!(
+            full.quote_deposit(MIN_DEPOSIT_AMOUNT).unwrap_err(),
+            error!(VultrError::ExceedsMaxPoolSize)
+        );
+    }
+
+    #[test]
+    fn quote_withdrawal_checks_supply_and_rounds_down() {
+        let pool = Pool {
+            total_deposits: 2_000_000_001,
+            total_shares: 1_000_000_000,
+            ..Pool::default()
+        };
+        assert_eq!(pool.quote_withdrawal(3).unwrap(), (6, 0));
+        assert_eq!(pool.quote_withdrawal(0).unwrap_err(), error!(VultrError::InvalidAmount));
+        assert_eq!(
+            pool.quote_withdrawal(1_000_000_001).unwrap_err(),
+            error!(VultrError::InsufficientShares)
+        );
+    }
+
+    #[test]
+    fn withdrawal_fee_stays_in_pool_except_on_full_exit() {
+        let pool = Pool {
+            withdrawal_fee_bps: 100,
+            ..pool_with_shares(1_000_000_000)
+        };
+        assert_eq!(pool.quote_withdrawal(10_000_000).unwrap(), (9_900_000, 100_000));
+
+        // A fractional fee rounds up: 1% of 1_000_001 is 10_000.01
+        assert_eq!(pool.quote_withdrawal(1_000_001).unwrap(), (990_000, 10_001));
+
+        // The last shareholder out pays nothing - no one is left to keep it for
+        assert_eq!(pool.quote_withdrawal(1_000_000_000).unwrap(), (1_000_000_000, 0));
+    }
+
+    #[test]
+    fn mul_div_rounds_in_the_documented_direction() {
+        assert_eq!(mul_div_floor(10, 1, 3).unwrap(), 3);
+        assert_eq!(mul_div_ceil(10, 1, 3).unwrap(), 4);
+        assert_eq!(mul_div_floor(9, 1, 3).unwrap(), 3);
+        assert_eq!(mul_div_ceil(9, 1, 3).unwrap(), 3);
+        assert!(mul_div_floor(1, 1, 0).is_err());
+        assert!(mul_div_ceil(1, 1, 0).is_err());
+        assert!(mul_div_floor(u64::MAX, 2, 1).is_err());
+    }
+
+    /// Deterministic xorshift so the property tests need no extra crates
+    fn next_rand(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn tiny_round_trips_never_extract_value() {
+        let mut seed = 0x9E37_79B9_7F4A_7C15;
+
+        for _ in 0..200 {
+            // Start from an uneven share price (profit has accrued)
+            let total_shares = 1_000_000_000 + next_rand(&mut seed) % 1_000_000_000_000;
+            let total_deposits = total_shares + next_rand(&mut seed) % total_shares;
+            let mut pool = Pool {
+                total_deposits,
+                total_shares,
+                ..Pool::default()
+            };
+
+            for _ in 0..500 {
+                let amount = 1 + next_rand(&mut seed) % 10_000;
+                let shares = pool.calculate_shares_to_mint(amount).unwrap();
+                pool.total_deposits += amount;
+                pool.total_shares += shares;
+
+                let out = pool.calculate_withdrawal_amount(shares).unwrap();
+                pool.total_deposits -= out;
+                pool.total_shares -= shares;
+
+                assert!(out <= amount, "round trip returned {} for {}", out, amount);
+            }
+
+            // Existing shareholders are never diluted: value per share is
+            // non-decreasing (compared by cross-multiplication)
+            assert!(
+                (pool.total_deposits as u128) * (total_shares as u128)
+                    >= (total_deposits as u128) * (pool.total_shares as u128)
+            );
+        }
+    }
+
+    #[test]
+    fn deposit_then_withdraw_never_exceeds_deposit() {
+        let mut seed = 0xD1B5_4A32_D192_ED03;
+
+        for _ in 0..10_000 {
+            let total_shares = 1 + next_rand(&mut seed) % 1_000_000_000_000;
+            let total_deposits = 1 + next_rand(&mut seed) % 1_000_000_000_000;
+            let amount = next_rand(&mut seed) % 1_000_000_000;
+            let mut pool = Pool {
+                total_deposits,
+                total_shares,
+                ..Pool::default()
+            };
+
+            let shares = pool.calculate_shares_to_mint(amount).unwrap();
+            pool.total_deposits += amount;
+            pool.total_shares += shares;
+
+            assert!(pool.calculate_withdrawal_amount(shares).unwrap() <= amount);
+        }
+    }
+
+    #[test]
+    fn empty_pool_prices_shares_at_one_and_reports_utilization() {
+        let mut pool = Pool {
+            max_pool_size: 4_000_000,
+            ..Pool::default()
+        };
+        assert_eq!(pool.share_price().unwrap(), SHARE_PRICE_PRECISION);
+        assert_eq!(pool.utilization_bps().unwrap(), 0);
+
+        pool.total_deposits = 1_000_000;
+        pool.total_shares = 1_000_000;
+        assert_eq!(pool.share_price().unwrap(), SHARE_PRICE_PRECISION);
+        assert_eq!(pool.utilization_bps().unwrap(), 2_500);
+
+        pool.max_pool_size = 0;
+        assert_eq!(pool.utilization_bps().unwrap(), 0);
+    }
+
+    #[test]
+    fn received_deposit_is_repriced_and_keeps_share_floor() {
+        // 2:1 share price
+        let pool = Pool {
+            total_deposits: 2_000_000_000,
+            ..pool_with_shares(1_000_000_000)
+        };
+        assert_eq!(pool.quote_received_deposit(990_000).unwrap(), 495_000);
+        assert_eq!(
+            pool.quote_received_deposit(MIN_SHARES_MINTED).unwrap_err(),
+            error!(VultrError::ShareAmountZero)
+        );
+    }
+
+    #[test]
+    fn pause_flags_stop_only_their_operation() {
+        let mut pool = Pool {
+            pause_flags: PAUSE_DEPOSITS | PAUSE_LIQUIDATIONS,
+            ..Pool::default()
+        };
+        assert!(pool.deposits_paused());
+        assert!(!pool.withdrawals_paused());
+        assert!(pool.liquidations_paused());
+
+        pool.pause_flags = 0;
+        pool.is_paused = true;
+        assert!(pool.deposits_paused());
+        assert!(pool.withdrawals_paused());
+        assert!(pool.liquidations_paused());
+    }
+}
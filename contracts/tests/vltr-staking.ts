@@ -9,12 +9,15 @@ import {
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
   createMint,
   createAccount,
   createAssociatedTokenAccount,
   getOrCreateAssociatedTokenAccount,
   mintTo,
   getAccount,
+  getAssociatedTokenAddressSync,
+  transfer,
 } from "@solana/spl-token";
 import { assert } from "chai";
 
@@ -221,6 +224,159 @@ describe("vltr-staking", () => {
     console.log("==================\n");
   });
 
+  // ==========================================================================
+  // Isolated pool helpers
+  // ==========================================================================
+  // Each isolated pool has its own VLTR mint (one staking pool per mint), so
+  // tests that need exact reward accounting don't depend on shared state.
+
+  interface IsolatedPool {
+    vltrMint: PublicKey;
+    stakingPool: PublicKey;
+    stakeVault: PublicKey;
+    rewardVault: PublicKey;
+  }
+
+  async function createIsolatedPool(): Promise<IsolatedPool> {
+    const isoVltrMint = await createMint(
+      provider.connection,
+      admin,
+      admin.publicKey,
+      null,
+      VLTR_DECIMALS
+    );
+    const isoRewardVault = await createAccount(
+      provider.connection,
+      admin,
+      usdcMint,
+      admin.publicKey,
+      Keypair.generate()
+    );
+    const [isoStakingPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("staking_pool"), isoVltrMint.toBuffer()],
+      program.programId
+    );
+    const [isoStakeVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_vault"), isoStakingPool.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initialize()
+      .accountsStrict({
+        admin: admin.publicKey,
+        stakingPool: isoStakingPool,
+        vltrMint: isoVltrMint,
+        rewardMint: usdcMint,
+        stakeVault: isoStakeVault,
+        rewardVault: isoRewardVault,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+    return {
+      vltrMint: isoVltrMint,
+      stakingPool: isoStakingPool,
+      stakeVault: isoStakeVault,
+      rewardVault: isoRewardVault,
+    };
+  }
+
+  function findStaker(pool: IsolatedPool, user: Keypair): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("staker"),
+        pool.stakingPool.toBuffer(),
+        user.publicKey.toBuffer(),
+      ],
+      program.programId
+    )[0];
+  }
+
+  async function vltrAccountFor(pool: IsolatedPool, user: Keypair): Promise<PublicKey> {
+    return (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        admin,
+        pool.vltrMint,
+        user.publicKey
+      )
+    ).address;
+  }
+
+  async function mintAndStake(pool: IsolatedPool, user: Keypair, amount: number) {
+    const userVltr = await vltrAccountFor(pool, user);
+    await mintTo(provider.connection, admin, pool.vltrMint, userVltr, admin, amount);
+
+    await program.methods
+      .stake(new anchor.BN(amount))
+      .accountsStrict({
+        user: user.publicKey,
+        stakingPool: pool.stakingPool,
+        staker: findStaker(pool, user),
+        vltrMint: pool.vltrMint,
+        userVltrAccount: userVltr,
+        stakeVault: pool.stakeVault,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  async function unstakeFrom(pool: IsolatedPool, user: Keypair, amount: number) {
+    await program.methods
+      .unstake(new anchor.BN(amount))
+      .accountsStrict({
+        user: user.publicKey,
+        stakingPool: pool.stakingPool,
+        staker: findStaker(pool, user),
+        vltrMint: pool.vltrMint,
+        userVltrAccount: await vltrAccountFor(pool, user),
+        stakeVault: pool.stakeVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  async function distributeTo(pool: IsolatedPool, amount: number) {
+    await mintTo(provider.connection, admin, usdcMint, adminUsdcAccount, admin, amount);
+
+    await program.methods
+      .distribute(new anchor.BN(amount))
+      .accountsStrict({
+        authority: admin.publicKey,
+        stakingPool: pool.stakingPool,
+        rewardMint: usdcMint,
+        rewardSource: adminUsdcAccount,
+        rewardVault: pool.rewardVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+  }
+
+  /** Claim from an isolated pool whose reward vault is still owned by admin */
+  async function claimFrom(pool: IsolatedPool, user: Keypair, userUsdc: PublicKey) {
+    await program.methods
+      .claim()
+      .accountsStrict({
+        user: user.publicKey,
+        stakingPool: pool.stakingPool,
+        staker: findStaker(pool, user),
+        rewardMint: usdcMint,
+        userRewardAccount: userUsdc,
+        rewardVault: pool.rewardVault,
+        rewardVaultAuthority: admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user, admin])
+      .rpc();
+  }
+
   describe("Initialize Staking Pool", () => {
     it("should initialize staking pool", async () => {
       await program.methods
@@ -455,6 +611,51 @@ describe("vltr-staking", () => {
         console.log("✅ Correctly rejected distribute from non-admin");
       }
     });
+
+    it("should reject a distribution above MAX_REWARD_PER_DISTRIBUTION", async () => {
+      const maxRewardPerDistribution = new anchor.BN(10_000_000).mul(new anchor.BN(10 ** USDC_DECIMALS));
+      try {
+        await program.methods
+          .distribute(maxRewardPerDistribution.addn(1))
+          .accountsStrict({
+            authority: admin.publicKey,
+            stakingPool: stakingPool,
+            rewardMint: usdcMint,
+            rewardSource: adminUsdcAccount,
+            rewardVault: rewardVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "ExceedsMaxDistribution");
+        console.log("✅ Correctly rejected distribution above the cap");
+      }
+    });
+
+    it("should fail to distribute when nothing is staked, leaving the source untouched", async () => {
+      const empty = await createIsolatedPool();
+      const sourceBefore = await getAccount(provider.connection, adminUsdcAccount);
+
+      try {
+        await distributeTo(empty, 1_000 * 10 ** USDC_DECIMALS);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "NoStakersToDistribute");
+      }
+
+      // distributeTo mints into the source first; nothing may leave it
+      const sourceAfter = await getAccount(provider.connection, adminUsdcAccount);
+      assert.equal(
+        Number(sourceAfter.amount) - Number(sourceBefore.amount),
+        1_000 * 10 ** USDC_DECIMALS,
+        "Rewards should stay in the source"
+      );
+      const vault = await getAccount(provider.connection, empty.rewardVault);
+      assert.equal(Number(vault.amount), 0);
+      console.log("✅ Correctly rejected distribute with no stakers");
+    });
   });
 
   describe("Claim Rewards", () => {
@@ -801,4 +1002,1636 @@ describe("vltr-staking", () => {
       console.log("========================\n");
     });
   });
+
+  describe("Claim With Account Creation", () => {
+    let user3: Keypair;
+    let user3VltrAccount: PublicKey;
+    let user3Staker: PublicKey;
+    let user3UsdcAta: PublicKey;
+
+    before(async () => {
+      user3 = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(user3.publicKey, 10 * LAMPORTS_PER_SOL)
+      );
+
+      // user3 holds VLTR but has never held USDC
+      user3VltrAccount = (
+        await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          admin,
+          vltrMint,
+          user3.publicKey
+        )
+      ).address;
+      await mintTo(
+        provider.connection,
+        admin,
+        vltrMint,
+        user3VltrAccount,
+        admin,
+        10_000 * 10 ** VLTR_DECIMALS
+      );
+
+      [user3Staker] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("staker"),
+          stakingPool.toBuffer(),
+          user3.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      user3UsdcAta = getAssociatedTokenAddressSync(usdcMint, user3.publicKey);
+
+      await program.methods
+        .stake(new anchor.BN(10_000 * 10 ** VLTR_DECIMALS))
+        .accountsStrict({
+          user: user3.publicKey,
+          stakingPool: stakingPool,
+          staker: user3Staker,
+          vltrMint: vltrMint,
+          userVltrAccount: user3VltrAccount,
+          stakeVault: stakeVault,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user3])
+        .rpc();
+
+      const reward = 1_000 * 10 ** USDC_DECIMALS;
+      await mintTo(
+        provider.connection,
+        admin,
+        usdcMint,
+        adminUsdcAccount,
+        admin,
+        reward
+      );
+      await program.methods
+        .distribute(new anchor.BN(reward))
+        .accountsStrict({
+          authority: admin.publicKey,
+          stakingPool: stakingPool,
+          rewardMint: usdcMint,
+          rewardSource: adminUsdcAccount,
+          rewardVault: rewardVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("should create the reward ATA and pay rewards for a user with no USDC account", async () => {
+      const ataBefore = await provider.connection.getAccountInfo(user3UsdcAta);
+      assert.isNull(ataBefore, "user3 should not have a USDC account yet");
+
+      await program.methods
+        .claimWithInit()
+        .accountsStrict({
+          user: user3.publicKey,
+          stakingPool: stakingPool,
+          staker: user3Staker,
+          rewardMint: usdcMint,
+          userRewardAccount: user3UsdcAta,
+          rewardVault: rewardVault,
+          rewardVaultAuthority: rewardVaultOwner.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([user3, rewardVaultOwner])
+        .rpc();
+
+      const ataAfter = await getAccount(provider.connection, user3UsdcAta);
+      const stakerAfter = await program.account.staker.fetch(user3Staker);
+
+      assert.equal(ataAfter.owner.toBase58(), user3.publicKey.toBase58());
+      assert.isAbove(Number(ataAfter.amount), 0, "ATA should be funded");
+      assert.equal(
+        Number(ataAfter.amount),
+        stakerAfter.rewardsClaimed.toNumber(),
+        "ATA balance should equal claimed rewards"
+      );
+
+      console.log(`✅ Created ATA and claimed ${Number(ataAfter.amount) / 10 ** USDC_DECIMALS} USDC`);
+    });
+  });
+
+  describe("Reward Vault Authority Rotation", () => {
+    // Isolated pool so the shared pool's externally-owned reward vault is
+    // left untouched
+    let rot: IsolatedPool;
+    const rotReward = 1_000 * 10 ** USDC_DECIMALS;
+
+    before(async () => {
+      rot = await createIsolatedPool();
+      await mintAndStake(rot, user1, 10_000 * 10 ** VLTR_DECIMALS);
+      await distributeTo(rot, rotReward);
+    });
+
+    it("should fail to rotate the authority from non-admin", async () => {
+      try {
+        await program.methods
+          .setRewardVaultAuthority()
+          .accountsStrict({
+            admin: user1.publicKey,
+            currentAuthority: admin.publicKey,
+            stakingPool: rot.stakingPool,
+            rewardVault: rot.rewardVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user1, admin])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "Unauthorized");
+        console.log("✅ Correctly rejected authority rotation from non-admin");
+      }
+    });
+
+    it("should rotate the reward vault authority to the staking pool PDA", async () => {
+      await program.methods
+        .setRewardVaultAuthority()
+        .accountsStrict({
+          admin: admin.publicKey,
+          currentAuthority: admin.publicKey,
+          stakingPool: rot.stakingPool,
+          rewardVault: rot.rewardVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      const vault = await getAccount(provider.connection, rot.rewardVault);
+      assert.equal(vault.owner.toBase58(), rot.stakingPool.toBase58());
+
+      console.log("✅ Reward vault now owned by staking pool PDA");
+    });
+
+    it("should let users claim with PDA signing and no vault owner co-signature", async () => {
+      const before = await getAccount(provider.connection, user1UsdcAccount);
+
+      await program.methods
+        .claim()
+        .accountsStrict({
+          user: user1.publicKey,
+          stakingPool: rot.stakingPool,
+          staker: findStaker(rot, user1),
+          rewardMint: usdcMint,
+          userRewardAccount: user1UsdcAccount,
+          rewardVault: rot.rewardVault,
+          rewardVaultAuthority: rot.stakingPool,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      const after = await getAccount(provider.connection, user1UsdcAccount);
+      const claimed = Number(after.amount) - Number(before.amount);
+      assert.isAtLeast(claimed, rotReward - 1, "Sole staker should receive the full reward");
+
+      console.log(`✅ PDA-signed claim paid ${claimed / 10 ** USDC_DECIMALS} USDC`);
+    });
+
+    it("should fail to claim from an externally-owned vault without the owner signing", async () => {
+      try {
+        await program.methods
+          .claim()
+          .accountsStrict({
+            user: user2.publicKey,
+            stakingPool: stakingPool,
+            staker: user2Staker,
+            rewardMint: usdcMint,
+            userRewardAccount: user2UsdcAccount,
+            rewardVault: rewardVault,
+            rewardVaultAuthority: rewardVaultOwner.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user2])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err, "Claim without vault owner signature should be rejected");
+        console.log("✅ Correctly required vault owner signature");
+      }
+    });
+  });
+
+  describe("Reward Banking Across Stake Changes", () => {
+    let bank: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const reward = 1_000 * 10 ** USDC_DECIMALS;
+
+    before(async () => {
+      bank = await createIsolatedPool();
+    });
+
+    it("should lose no rewards when stake, distribute, unstake and claim interleave", async () => {
+      // user1 alone earns the first distribution
+      await mintAndStake(bank, user1, stakeUnit);
+      await distributeTo(bank, reward);
+
+      // user1 adds stake WITHOUT claiming first - accrual must be banked
+      await mintAndStake(bank, user1, stakeUnit);
+      let staker1 = await program.account.staker.fetch(findStaker(bank, user1));
+      assert.equal(staker1.pendingUnclaimed.toNumber(), reward, "First reward should be banked");
+
+      // user2 joins with equal stake; second distribution splits 50/50
+      await mintAndStake(bank, user2, 2 * stakeUnit);
+      await distributeTo(bank, reward);
+
+      // user1 fully exits WITHOUT claiming - banked + fresh must survive
+      await unstakeFrom(bank, user1, 2 * stakeUnit);
+      staker1 = await program.account.staker.fetch(findStaker(bank, user1));
+      assert.equal(staker1.stakedAmount.toNumber(), 0);
+
+      // Third distribution goes entirely to user2
+      await distributeTo(bank, reward);
+
+      const user1Before = Number((await getAccount(provider.connection, user1UsdcAccount)).amount);
+      const user2Before = Number((await getAccount(provider.connection, user2UsdcAccount)).amount);
+
+      await claimFrom(bank, user1, user1UsdcAccount);
+      await claimFrom(bank, user2, user2UsdcAccount);
+
+      const user1Claimed =
+        Number((await getAccount(provider.connection, user1UsdcAccount)).amount) - user1Before;
+      const user2Claimed =
+        Number((await getAccount(provider.connection, user2UsdcAccount)).amount) - user2Before;
+
+      const expected = reward + reward / 2;
+      assert.approximately(user1Claimed, expected, 2, "user1: full first reward + half of second");
+      assert.approximately(user2Claimed, expected, 2, "user2: half of second + full third");
+      assert.isAtMost(user1Claimed + user2Claimed, 3 * reward, "Never pay out more than distributed");
+
+      staker1 = await program.account.staker.fetch(findStaker(bank, user1));
+      assert.equal(staker1.pendingUnclaimed.toNumber(), 0, "Banked rewards cleared on claim");
+
+      console.log(`✅ user1 claimed ${user1Claimed / 10 ** USDC_DECIMALS} USDC after exiting`);
+      console.log(`✅ user2 claimed ${user2Claimed / 10 ** USDC_DECIMALS} USDC`);
+    });
+  });
+
+  describe("Reward Solvency", () => {
+    let solvency: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const reward = 1_000 * 10 ** USDC_DECIMALS;
+
+    const getSolvency = () =>
+      program.methods
+        .getRewardSolvency()
+        .accountsStrict({
+          stakingPool: solvency.stakingPool,
+          rewardVault: solvency.rewardVault,
+        })
+        .view();
+
+    before(async () => {
+      solvency = await createIsolatedPool();
+      await mintAndStake(solvency, user1, stakeUnit);
+    });
+
+    it("should report a fully funded vault as solvent", async () => {
+      await distributeTo(solvency, reward);
+
+      const result = await getSolvency();
+      assert.equal(result.totalRewardsOwed.toNumber(), reward);
+      assert.equal(result.rewardVaultBalance.toNumber(), reward);
+      assert.isTrue(result.isSolvent);
+      assert.equal(result.shortfall.toNumber(), 0);
+
+      console.log("✅ Funded vault reported solvent");
+    });
+
+    it("should flag an under-funded vault and report the shortfall", async () => {
+      // Pull funds out of the admin-owned vault behind the program's back
+      const removed = reward / 4;
+      await transfer(
+        provider.connection,
+        admin,
+        solvency.rewardVault,
+        adminUsdcAccount,
+        admin,
+        removed
+      );
+
+      const result = await getSolvency();
+      assert.isFalse(result.isSolvent);
+      assert.equal(result.shortfall.toNumber(), removed);
+
+      console.log(`✅ Shortfall of ${removed / 10 ** USDC_DECIMALS} USDC detected`);
+    });
+
+    it("should reduce rewards owed when stakers claim", async () => {
+      // Top the vault back up, then claim everything
+      await mintTo(provider.connection, admin, usdcMint, solvency.rewardVault, admin, reward / 4);
+      await claimFrom(solvency, user1, user1UsdcAccount);
+
+      const result = await getSolvency();
+      assert.isAtMost(result.totalRewardsOwed.toNumber(), 1, "Only rounding dust left owed");
+      assert.isTrue(result.isSolvent);
+
+      console.log("✅ Claims reduce rewards owed");
+    });
+  });
+
+  describe("Decimals-Aware Minimum Distribution", () => {
+    const REWARD_DECIMALS_8 = 8;
+    let pool8: IsolatedPool;
+    let rewardMint8: PublicKey;
+    let adminReward8: PublicKey;
+
+    const distribute8 = (amount: number) =>
+      program.methods
+        .distribute(new anchor.BN(amount))
+        .accountsStrict({
+          authority: admin.publicKey,
+          stakingPool: pool8.stakingPool,
+          rewardMint: rewardMint8,
+          rewardSource: adminReward8,
+          rewardVault: pool8.rewardVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+    before(async () => {
+      rewardMint8 = await createMint(
+        provider.connection,
+        admin,
+        admin.publicKey,
+        null,
+        REWARD_DECIMALS_8
+      );
+      adminReward8 = await createAccount(
+        provider.connection,
+        admin,
+        rewardMint8,
+        admin.publicKey,
+        Keypair.generate()
+      );
+      await mintTo(provider.connection, admin, rewardMint8, adminReward8, admin, 10 ** 12);
+
+      const vltrMint8 = await createMint(
+        provider.connection,
+        admin,
+        admin.publicKey,
+        null,
+        VLTR_DECIMALS
+      );
+      const rewardVault8 = await createAccount(
+        provider.connection,
+        admin,
+        rewardMint8,
+        admin.publicKey,
+        Keypair.generate()
+      );
+      const [stakingPool8] = PublicKey.findProgramAddressSync(
+        [Buffer.from("staking_pool"), vltrMint8.toBuffer()],
+        program.programId
+      );
+      const [stakeVault8] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), stakingPool8.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initialize()
+        .accountsStrict({
+          admin: admin.publicKey,
+          stakingPool: stakingPool8,
+          vltrMint: vltrMint8,
+          rewardMint: rewardMint8,
+          stakeVault: stakeVault8,
+          rewardVault: rewardVault8,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      pool8 = {
+        vltrMint: vltrMint8,
+        stakingPool: stakingPool8,
+        stakeVault: stakeVault8,
+        rewardVault: rewardVault8,
+      };
+      await mintAndStake(pool8, user1, 10_000 * 10 ** VLTR_DECIMALS);
+    });
+
+    it("should derive the minimum from the reward mint's decimals", async () => {
+      const usdcPool = await program.account.stakingPool.fetch(stakingPool);
+      assert.equal(usdcPool.minDistribute.toNumber(), 1_000, "0.001 USDC at 6 decimals");
+
+      const pool = await program.account.stakingPool.fetch(pool8.stakingPool);
+      assert.equal(pool.minDistribute.toNumber(), 100_000, "0.001 tokens at 8 decimals");
+
+      console.log("✅ Minimum distribution scales with reward decimals");
+    });
+
+    it("should reject a distribution below the scaled minimum", async () => {
+      try {
+        await distribute8(99_999);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "InvalidAmount");
+        console.log("✅ Correctly rejected sub-minimum distribution");
+      }
+    });
+
+    it("should accept a distribution at the scaled minimum", async () => {
+      await distribute8(100_000);
+
+      const pool = await program.account.stakingPool.fetch(pool8.stakingPool);
+      assert.equal(pool.totalRewardsDistributed.toNumber(), 100_000);
+
+      console.log("✅ Distribution at the minimum accepted");
+    });
+  });
+
+  describe("Launch Boost", () => {
+    let boosted: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const reward = 1_000 * 10 ** USDC_DECIMALS;
+    const boostWindowSeconds = 6;
+
+    const usdcBalance = async (account: PublicKey) =>
+      Number((await getAccount(provider.connection, account)).amount);
+
+    const claimDelta = async (user: Keypair, userUsdc: PublicKey) => {
+      const before = await usdcBalance(userUsdc);
+      await claimFrom(boosted, user, userUsdc);
+      return (await usdcBalance(userUsdc)) - before;
+    };
+
+    before(async () => {
+      boosted = await createIsolatedPool();
+    });
+
+    it("should reject an invalid boost configuration", async () => {
+      const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+      try {
+        await program.methods
+          .setLaunchBoost(40_000, new anchor.BN(now + 3600)) // 4x > max
+          .accountsStrict({ admin: admin.publicKey, stakingPool: boosted.stakingPool })
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "InvalidBoostConfig");
+        console.log("✅ Correctly rejected boost above maximum");
+      }
+    });
+
+    it("should pay an early staker more during the boost and normally afterward", async () => {
+      const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+      await program.methods
+        .setLaunchBoost(20_000, new anchor.BN(now + boostWindowSeconds)) // 2x
+        .accountsStrict({ admin: admin.publicKey, stakingPool: boosted.stakingPool })
+        .signers([admin])
+        .rpc();
+
+      // user1 joins inside the boost window
+      await mintAndStake(boosted, user1, stakeUnit);
+      let staker1 = await program.account.staker.fetch(findStaker(boosted, user1));
+      assert.isAbove(staker1.effectiveStake.toNumber(), stakeUnit, "Early stake should be boosted");
+
+      // Let the boost expire, then user2 joins at 1x with the same stake
+      await new Promise((resolve) => setTimeout(resolve, (boostWindowSeconds + 2) * 1000));
+      await mintAndStake(boosted, user2, stakeUnit);
+      const staker2 = await program.account.staker.fetch(findStaker(boosted, user2));
+      assert.equal(staker2.effectiveStake.toNumber(), stakeUnit, "Late stake should be 1x");
+
+      await distributeTo(boosted, reward);
+      const early1 = await claimDelta(user1, user1UsdcAccount);
+      const early2 = await claimDelta(user2, user2UsdcAccount);
+      assert.isAbove(early1, early2, "Boosted staker should earn more");
+      assert.isAtMost(early1 + early2, reward, "Never pay out more than distributed");
+
+      // Anyone can poke user1 back down to 1x once the boost has ended
+      await program.methods
+        .poke()
+        .accountsStrict({ stakingPool: boosted.stakingPool, staker: findStaker(boosted, user1) })
+        .rpc();
+      staker1 = await program.account.staker.fetch(findStaker(boosted, user1));
+      assert.equal(staker1.effectiveStake.toNumber(), stakeUnit, "Poke should remove expired boost");
+
+      const pool = await program.account.stakingPool.fetch(boosted.stakingPool);
+      assert.equal(pool.totalEffectiveStake.toNumber(), 2 * stakeUnit);
+
+      await distributeTo(boosted, reward);
+      const after1 = await claimDelta(user1, user1UsdcAccount);
+      const after2 = await claimDelta(user2, user2UsdcAccount);
+      assert.approximately(after1, after2, 2, "Equal stakes earn equally after the boost");
+
+      console.log(`✅ During boost: user1 ${early1}, user2 ${early2}`);
+      console.log(`✅ After boost: user1 ${after1}, user2 ${after2}`);
+    });
+  });
+
+  describe("Unstake Cooldown", () => {
+    let cooled: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const cooldownSeconds = 3;
+
+    const requestUnstake = (user: Keypair) =>
+      program.methods
+        .requestUnstake()
+        .accountsStrict({
+          user: user.publicKey,
+          stakingPool: cooled.stakingPool,
+          staker: findStaker(cooled, user),
+        })
+        .signers([user])
+        .rpc();
+
+    before(async () => {
+      cooled = await createIsolatedPool();
+      await mintAndStake(cooled, user1, stakeUnit);
+      await program.methods
+        .setUnstakeCooldown(new anchor.BN(cooldownSeconds))
+        .accountsStrict({ admin: admin.publicKey, stakingPool: cooled.stakingPool })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("should reject a cooldown above the maximum", async () => {
+      try {
+        await program.methods
+          .setUnstakeCooldown(new anchor.BN(31 * 24 * 60 * 60))
+          .accountsStrict({ admin: admin.publicKey, stakingPool: cooled.stakingPool })
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "InvalidUnstakeCooldown");
+        console.log("✅ Correctly rejected cooldown above maximum");
+      }
+    });
+
+    it("should fail to unstake without a request", async () => {
+      try {
+        await unstakeFrom(cooled, user1, stakeUnit);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "UnstakeNotRequested");
+        console.log("✅ Correctly rejected unstake without request");
+      }
+    });
+
+    it("should fail to unstake before the cooldown elapses", async () => {
+      await requestUnstake(user1);
+
+      try {
+        await unstakeFrom(cooled, user1, stakeUnit);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "UnstakeCooldownNotElapsed");
+        console.log("✅ Correctly rejected unstake during cooldown");
+      }
+    });
+
+    it("should restart the cooldown when more is staked", async () => {
+      await new Promise((resolve) => setTimeout(resolve, (cooldownSeconds + 2) * 1000));
+      await mintAndStake(cooled, user1, stakeUnit);
+
+      const staker = await program.account.staker.fetch(findStaker(cooled, user1));
+      assert.equal(staker.unstakeAvailableAt.toNumber(), 0, "Stake should clear the request");
+
+      try {
+        await unstakeFrom(cooled, user1, stakeUnit);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "UnstakeNotRequested");
+        console.log("✅ New stake cleared the pending unstake request");
+      }
+    });
+
+    it("should unstake once the cooldown has elapsed", async () => {
+      await requestUnstake(user1);
+      await new Promise((resolve) => setTimeout(resolve, (cooldownSeconds + 2) * 1000));
+
+      await unstakeFrom(cooled, user1, 2 * stakeUnit);
+
+      const staker = await program.account.staker.fetch(findStaker(cooled, user1));
+      assert.equal(staker.stakedAmount.toNumber(), 0);
+      assert.equal(staker.unstakeAvailableAt.toNumber(), 0, "Unstake should consume the request");
+
+      console.log("✅ Unstaked after cooldown");
+    });
+  });
+
+  describe("Minimum Stake Duration For Rewards", () => {
+    let gated: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const reward = 1_000 * 10 ** USDC_DECIMALS;
+    const minDurationSeconds = 4;
+
+    before(async () => {
+      gated = await createIsolatedPool();
+      await program.methods
+        .setMinStakeDuration(new anchor.BN(minDurationSeconds))
+        .accountsStrict({ admin: admin.publicKey, stakingPool: gated.stakingPool })
+        .signers([admin])
+        .rpc();
+
+      // user2 is a long-standing staker by the time rewards arrive
+      await mintAndStake(gated, user2, stakeUnit);
+      await new Promise((resolve) => setTimeout(resolve, (minDurationSeconds + 2) * 1000));
+    });
+
+    it("should forfeit rewards of a staker who exits right after a distribution", async () => {
+      // user1 snipes: stake just before distribute, unstake right after
+      await mintAndStake(gated, user1, stakeUnit);
+      await distributeTo(gated, reward);
+
+      try {
+        await program.methods
+          .poke()
+          .accountsStrict({ stakingPool: gated.stakingPool, staker: findStaker(gated, user1) })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "RewardsNotYetEligible");
+      }
+
+      await unstakeFrom(gated, user1, stakeUnit);
+
+      const staker1 = await program.account.staker.fetch(findStaker(gated, user1));
+      assert.equal(staker1.pendingUnclaimed.toNumber(), 0, "Sniper should keep nothing");
+
+      const pool = await program.account.stakingPool.fetch(gated.stakingPool);
+      assert.approximately(pool.totalForfeited.toNumber(), reward / 2, 1);
+
+      // The forfeited half rolls to user2
+      const before = Number((await getAccount(provider.connection, user2UsdcAccount)).amount);
+      await claimFrom(gated, user2, user2UsdcAccount);
+      const after = Number((await getAccount(provider.connection, user2UsdcAccount)).amount);
+      assert.approximately(after - before, reward, 2, "Remaining staker should get the full reward");
+
+      console.log(`✅ Forfeited ${pool.totalForfeited.toNumber()} from sniper, user2 claimed ${after - before}`);
+    });
+  });
+
+  describe("Claim And Restake", () => {
+    let compounding: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const reward = 500 * 10 ** VLTR_DECIMALS;
+
+    before(async () => {
+      // A pool that pays its rewards in VLTR itself
+      const vltr = await createMint(provider.connection, admin, admin.publicKey, null, VLTR_DECIMALS);
+      const vltrRewardVault = await createAccount(
+        provider.connection,
+        admin,
+        vltr,
+        admin.publicKey,
+        Keypair.generate()
+      );
+      const [pool] = PublicKey.findProgramAddressSync(
+        [Buffer.from("staking_pool"), vltr.toBuffer()],
+        program.programId
+      );
+      const [vault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), pool.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initialize()
+        .accountsStrict({
+          admin: admin.publicKey,
+          stakingPool: pool,
+          vltrMint: vltr,
+          rewardMint: vltr,
+          stakeVault: vault,
+          rewardVault: vltrRewardVault,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      compounding = { vltrMint: vltr, stakingPool: pool, stakeVault: vault, rewardVault: vltrRewardVault };
+      await mintAndStake(compounding, user1, stakeUnit);
+
+      const adminVltr = await vltrAccountFor(compounding, admin);
+      await mintTo(provider.connection, admin, vltr, adminVltr, admin, reward);
+      await program.methods
+        .distribute(new anchor.BN(reward))
+        .accountsStrict({
+          authority: admin.publicKey,
+          stakingPool: pool,
+          rewardMint: vltr,
+          rewardSource: adminVltr,
+          rewardVault: vltrRewardVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("should add pending rewards to the stake", async () => {
+      await program.methods
+        .claimAndRestake()
+        .accountsStrict({
+          user: user1.publicKey,
+          stakingPool: compounding.stakingPool,
+          staker: findStaker(compounding, user1),
+          vltrMint: compounding.vltrMint,
+          rewardVault: compounding.rewardVault,
+          rewardVaultAuthority: admin.publicKey,
+          stakeVault: compounding.stakeVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1, admin])
+        .rpc();
+
+      const staker = await program.account.staker.fetch(findStaker(compounding, user1));
+      const pool = await program.account.stakingPool.fetch(compounding.stakingPool);
+      const vault = await getAccount(provider.connection, compounding.stakeVault);
+      assert.approximately(staker.stakedAmount.toNumber(), stakeUnit + reward, 1);
+      assert.equal(pool.totalStaked.toNumber(), staker.stakedAmount.toNumber());
+      assert.equal(Number(vault.amount), pool.totalStaked.toNumber(), "Stake vault should hold the restaked rewards");
+
+      console.log(`✅ Restaked rewards: stake is now ${staker.stakedAmount.toNumber()}`);
+    });
+
+    it("should not let restaked rewards be claimed again", async () => {
+      try {
+        await program.methods
+          .claimAndRestake()
+          .accountsStrict({
+            user: user1.publicKey,
+            stakingPool: compounding.stakingPool,
+            staker: findStaker(compounding, user1),
+            vltrMint: compounding.vltrMint,
+            rewardVault: compounding.rewardVault,
+            rewardVaultAuthority: admin.publicKey,
+            stakeVault: compounding.stakeVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user1, admin])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "NoRewardsToClaim");
+        console.log("✅ Correctly rejected a second restake with nothing pending");
+      }
+    });
+
+    it("should neither forfeit rewards nor cancel a pending unstake", async () => {
+      const minDurationSeconds = 3;
+      const adminVltr = await vltrAccountFor(compounding, admin);
+      const distributeVltr = async (amount: number) => {
+        await mintTo(provider.connection, admin, compounding.vltrMint, adminVltr, admin, amount);
+        await program.methods
+          .distribute(new anchor.BN(amount))
+          .accountsStrict({
+            authority: admin.publicKey,
+            stakingPool: compounding.stakingPool,
+            rewardMint: compounding.vltrMint,
+            rewardSource: adminVltr,
+            rewardVault: compounding.rewardVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([admin])
+          .rpc();
+      };
+      const restake = () =>
+        program.methods
+          .claimAndRestake()
+          .accountsStrict({
+            user: user1.publicKey,
+            stakingPool: compounding.stakingPool,
+            staker: findStaker(compounding, user1),
+            vltrMint: compounding.vltrMint,
+            rewardVault: compounding.rewardVault,
+            rewardVaultAuthority: admin.publicKey,
+            stakeVault: compounding.stakeVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user1, admin])
+          .rpc();
+
+      await program.methods
+        .setMinStakeDuration(new anchor.BN(minDurationSeconds))
+        .accountsStrict({ admin: admin.publicKey, stakingPool: compounding.stakingPool })
+        .signers([admin])
+        .rpc();
+      await program.methods
+        .setUnstakeCooldown(new anchor.BN(24 * 60 * 60))
+        .accountsStrict({ admin: admin.publicKey, stakingPool: compounding.stakingPool })
+        .signers([admin])
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, (minDurationSeconds + 2) * 1000));
+
+      await program.methods
+        .requestUnstake()
+        .accountsStrict({
+          user: user1.publicKey,
+          stakingPool: compounding.stakingPool,
+          staker: findStaker(compounding, user1),
+        })
+        .signers([user1])
+        .rpc();
+      const before = await program.account.staker.fetch(findStaker(compounding, user1));
+
+      // Compound twice in a row: the second restake would forfeit if the
+      // first had restarted the minimum stake duration
+      for (let i = 0; i < 2; i++) {
+        await distributeVltr(reward);
+        await restake();
+      }
+
+      const after = await program.account.staker.fetch(findStaker(compounding, user1));
+      const pool = await program.account.stakingPool.fetch(compounding.stakingPool);
+      assert.equal(pool.totalForfeited.toNumber(), 0, "Compounding should forfeit nothing");
+      assert.approximately(
+        after.stakedAmount.toNumber() - before.stakedAmount.toNumber(),
+        2 * reward,
+        2
+      );
+      assert.equal(after.lastStakeTime.toNumber(), before.lastStakeTime.toNumber());
+      assert.equal(
+        after.unstakeAvailableAt.toNumber(),
+        before.unstakeAvailableAt.toNumber(),
+        "Pending unstake request should survive compounding"
+      );
+
+      console.log("✅ Compounding kept the stake timers and every reward");
+    });
+
+    it("should reject restaking less than the minimum stake", async () => {
+      // 0.5 VLTR of rewards: claimable, but below MIN_STAKE_AMOUNT
+      const adminVltr = await vltrAccountFor(compounding, admin);
+      const dust = 10 ** VLTR_DECIMALS / 2;
+      await mintTo(provider.connection, admin, compounding.vltrMint, adminVltr, admin, dust);
+      await program.methods
+        .distribute(new anchor.BN(dust))
+        .accountsStrict({
+          authority: admin.publicKey,
+          stakingPool: compounding.stakingPool,
+          rewardMint: compounding.vltrMint,
+          rewardSource: adminVltr,
+          rewardVault: compounding.rewardVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await program.methods
+          .claimAndRestake()
+          .accountsStrict({
+            user: user1.publicKey,
+            stakingPool: compounding.stakingPool,
+            staker: findStaker(compounding, user1),
+            vltrMint: compounding.vltrMint,
+            rewardVault: compounding.rewardVault,
+            rewardVaultAuthority: admin.publicKey,
+            stakeVault: compounding.stakeVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user1, admin])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "BelowMinimumStake");
+        console.log("✅ Correctly rejected restaking dust rewards");
+      }
+    });
+
+    it("should reject restaking in a pool paid in another mint", async () => {
+      try {
+        await program.methods
+          .claimAndRestake()
+          .accountsStrict({
+            user: user1.publicKey,
+            stakingPool: stakingPool,
+            staker: user1Staker,
+            vltrMint: vltrMint,
+            rewardVault: rewardVault,
+            rewardVaultAuthority: rewardVaultOwner.publicKey,
+            stakeVault: stakeVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user1, rewardVaultOwner])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "InvalidRewardMint");
+        console.log("✅ Correctly rejected restake of USDC rewards");
+      }
+    });
+  });
+
+  describe("Bonus Reward Tokens", () => {
+    let multi: IsolatedPool;
+    let bonusMint: PublicKey;
+    let bonusVault: PublicKey;
+    let adminBonus: PublicKey;
+    let user1Bonus: PublicKey;
+    let user2Bonus: PublicKey;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const bonusReward = 600 * 10 ** USDC_DECIMALS;
+
+    const claimBonus = (user: Keypair, userBonus: PublicKey, rewardIndex: number) =>
+      program.methods
+        .claimReward(rewardIndex)
+        .accountsStrict({
+          user: user.publicKey,
+          stakingPool: multi.stakingPool,
+          staker: findStaker(multi, user),
+          rewardMint: bonusMint,
+          userRewardAccount: userBonus,
+          rewardVault: bonusVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+
+    before(async () => {
+      multi = await createIsolatedPool();
+      bonusMint = await createMint(provider.connection, admin, admin.publicKey, null, USDC_DECIMALS);
+      [bonusVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), multi.stakingPool.toBuffer(), bonusMint.toBuffer()],
+        program.programId
+      );
+      adminBonus = (await getOrCreateAssociatedTokenAccount(provider.connection, admin, bonusMint, admin.publicKey)).address;
+      user1Bonus = (await getOrCreateAssociatedTokenAccount(provider.connection, admin, bonusMint, user1.publicKey)).address;
+      user2Bonus = (await getOrCreateAssociatedTokenAccount(provider.connection, admin, bonusMint, user2.publicKey)).address;
+
+      await mintAndStake(multi, user1, stakeUnit);
+      await mintAndStake(multi, user2, 2 * stakeUnit);
+    });
+
+    it("should register a bonus reward token at index 1", async () => {
+      await program.methods
+        .addRewardToken()
+        .accountsStrict({
+          admin: admin.publicKey,
+          stakingPool: multi.stakingPool,
+          rewardMint: bonusMint,
+          rewardVault: bonusVault,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      const pool = await program.account.stakingPool.fetch(multi.stakingPool);
+      assert.equal(pool.bonusRewards[0].mint.toBase58(), bonusMint.toBase58());
+      assert.equal(pool.bonusRewards[0].vault.toBase58(), bonusVault.toBase58());
+      console.log("✅ Bonus reward registered");
+    });
+
+    it("should split a bonus distribution pro-rata without touching the primary reward", async () => {
+      await mintTo(provider.connection, admin, bonusMint, adminBonus, admin, bonusReward);
+      await program.methods
+        .distributeReward(1, new anchor.BN(bonusReward))
+        .accountsStrict({
+          authority: admin.publicKey,
+          stakingPool: multi.stakingPool,
+          rewardMint: bonusMint,
+          rewardSource: adminBonus,
+          rewardVault: bonusVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      await claimBonus(user1, user1Bonus, 1);
+      await claimBonus(user2, user2Bonus, 1);
+
+      const got1 = Number((await getAccount(provider.connection, user1Bonus)).amount);
+      const got2 = Number((await getAccount(provider.connection, user2Bonus)).amount);
+      assert.approximately(got1, bonusReward / 3, 1);
+      assert.approximately(got2, (2 * bonusReward) / 3, 1);
+
+      const pool = await program.account.stakingPool.fetch(multi.stakingPool);
+      assert.equal(pool.totalRewardsDistributed.toNumber(), 0, "Primary reward should be untouched");
+
+      console.log(`✅ Bonus split: user1 ${got1}, user2 ${got2}`);
+    });
+
+    it("should reject an unconfigured reward index", async () => {
+      try {
+        await claimBonus(user1, user1Bonus, 2);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "InvalidRewardIndex");
+        console.log("✅ Correctly rejected unconfigured reward index");
+      }
+    });
+  });
+
+  describe("Streamed Distribution", () => {
+    let streamed: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const reward = 1_000 * 10 ** USDC_DECIMALS;
+    const durationSeconds = 60;
+
+    before(async () => {
+      streamed = await createIsolatedPool();
+      await mintAndStake(streamed, user1, stakeUnit);
+    });
+
+    it("should reject a stream longer than the maximum duration", async () => {
+      await mintTo(provider.connection, admin, usdcMint, adminUsdcAccount, admin, reward);
+      try {
+        await program.methods
+          .distributeStreamed(new anchor.BN(reward), new anchor.BN(366 * 24 * 60 * 60))
+          .accountsStrict({
+            authority: admin.publicKey,
+            stakingPool: streamed.stakingPool,
+            rewardMint: usdcMint,
+            rewardSource: adminUsdcAccount,
+            rewardVault: streamed.rewardVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "InvalidRewardDuration");
+        console.log("✅ Correctly rejected over-long stream");
+      }
+    });
+
+    it("should pay out rewards gradually instead of all at once", async () => {
+      await program.methods
+        .distributeStreamed(new anchor.BN(reward), new anchor.BN(durationSeconds))
+        .accountsStrict({
+          authority: admin.publicKey,
+          stakingPool: streamed.stakingPool,
+          rewardMint: usdcMint,
+          rewardSource: adminUsdcAccount,
+          rewardVault: streamed.rewardVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      let pool = await program.account.stakingPool.fetch(streamed.stakingPool);
+      assert.isTrue(pool.rewardRate.gtn(0), "Stream should set a reward rate");
+      assert.isAbove(pool.rewardPeriodFinish.toNumber(), 0);
+
+      // A sniper staking now only earns what streams after they join
+      await mintAndStake(streamed, user2, stakeUnit);
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      const before = Number((await getAccount(provider.connection, user2UsdcAccount)).amount);
+      await claimFrom(streamed, user2, user2UsdcAccount);
+      const sniped = Number((await getAccount(provider.connection, user2UsdcAccount)).amount) - before;
+
+      assert.isAbove(sniped, 0, "Streamed rewards should accrue over time");
+      assert.isBelow(sniped, reward / 4, "A late staker must not capture the stream");
+
+      pool = await program.account.stakingPool.fetch(streamed.stakingPool);
+      assert.isBelow(pool.totalRewardsDistributed.toNumber(), reward, "Stream should not be fully emitted yet");
+
+      console.log(`✅ Late staker earned ${sniped} of ${reward} streamed`);
+    });
+  });
+
+  describe("Stake Lockups", () => {
+    let locked: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const day = 24 * 60 * 60;
+
+    const lockStake = (user: Keypair, durationSeconds: number) =>
+      program.methods
+        .lockStake(new anchor.BN(durationSeconds))
+        .accountsStrict({
+          user: user.publicKey,
+          stakingPool: locked.stakingPool,
+          staker: findStaker(locked, user),
+        })
+        .signers([user])
+        .rpc();
+
+    before(async () => {
+      locked = await createIsolatedPool();
+      await mintAndStake(locked, user1, stakeUnit);
+      await mintAndStake(locked, user2, stakeUnit);
+    });
+
+    it("should reject a lock duration that is not a tier", async () => {
+      try {
+        await lockStake(user1, 45 * day);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "InvalidLockDuration");
+        console.log("✅ Correctly rejected non-tier lock duration");
+      }
+    });
+
+    it("should boost a locked position's reward weight", async () => {
+      await lockStake(user1, 90 * day);
+
+      const staker = await program.account.staker.fetch(findStaker(locked, user1));
+      assert.equal(staker.lockBoostBps, 15_000);
+      assert.equal(staker.effectiveStake.toNumber(), (stakeUnit * 3) / 2, "90-day lock should be 1.5x");
+
+      const pool = await program.account.stakingPool.fetch(locked.stakingPool);
+      assert.equal(pool.totalEffectiveStake.toNumber(), (stakeUnit * 5) / 2);
+
+      console.log(`✅ Locked until ${staker.lockUntil.toNumber()} with ${staker.lockBoostBps} bps`);
+    });
+
+    it("should reject unstaking before the lock expires", async () => {
+      try {
+        await unstakeFrom(locked, user1, stakeUnit);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "StakeLocked");
+        console.log("✅ Correctly rejected unstake of locked stake");
+      }
+    });
+
+    it("should reject shortening an existing lock", async () => {
+      try {
+        await lockStake(user1, 30 * day);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "LockCannotBeShortened");
+        console.log("✅ Correctly rejected shorter lock");
+      }
+    });
+  });
+
+  describe("Admin Transfer", () => {
+    let isolated: IsolatedPool;
+    const newAdmin = Keypair.generate();
+
+    const acceptAs = (signer: Keypair) =>
+      program.methods
+        .acceptAdminTransfer()
+        .accountsStrict({ newAdmin: signer.publicKey, stakingPool: isolated.stakingPool })
+        .signers([signer])
+        .rpc();
+
+    before(async () => {
+      isolated = await createIsolatedPool();
+      await program.methods
+        .proposeAdminTransfer()
+        .accountsStrict({
+          admin: admin.publicKey,
+          stakingPool: isolated.stakingPool,
+          newAdmin: newAdmin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("should record the proposal without changing the admin", async () => {
+      const poolAccount = await program.account.stakingPool.fetch(isolated.stakingPool);
+      assert.ok(poolAccount.admin.equals(admin.publicKey));
+      assert.ok(poolAccount.pendingAdmin.equals(newAdmin.publicKey));
+    });
+
+    it("should reject acceptance by anyone but the pending admin", async () => {
+      try {
+        await acceptAs(user1);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "NotPendingAdmin");
+        console.log("✅ Correctly rejected accept from non-pending admin");
+      }
+    });
+
+    it("should reject acceptance before the timelock", async () => {
+      try {
+        await acceptAs(newAdmin);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "TimelockNotExpired");
+        console.log("✅ Correctly rejected accept during timelock");
+      }
+    });
+
+    it("should let the admin cancel the proposal", async () => {
+      await program.methods
+        .cancelAdminTransfer()
+        .accountsStrict({ admin: admin.publicKey, stakingPool: isolated.stakingPool })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await acceptAs(newAdmin);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "NoPendingChange");
+        console.log("✅ Cancelled proposal can no longer be accepted");
+      }
+    });
+  });
+
+  describe("Batch Distribution", () => {
+    let first: IsolatedPool;
+    let second: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const reward = 1_000 * 10 ** USDC_DECIMALS;
+
+    const batchMetas = (pools: [PublicKey, PublicKey][]) =>
+      pools.flatMap(([pool, vault]) => [
+        { pubkey: pool, isWritable: true, isSigner: false },
+        { pubkey: vault, isWritable: true, isSigner: false },
+      ]);
+
+    before(async () => {
+      first = await createIsolatedPool();
+      second = await createIsolatedPool();
+      await mintAndStake(first, user1, stakeUnit);
+      await mintAndStake(second, user2, stakeUnit);
+    });
+
+    it("should distribute to several pools in one transaction", async () => {
+      await mintTo(provider.connection, admin, usdcMint, adminUsdcAccount, admin, 3 * reward);
+      await program.methods
+        .distributeBatch([new anchor.BN(reward), new anchor.BN(2 * reward)])
+        .accountsStrict({
+          authority: admin.publicKey,
+          rewardMint: usdcMint,
+          rewardSource: adminUsdcAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          batchMetas([
+            [first.stakingPool, first.rewardVault],
+            [second.stakingPool, second.rewardVault],
+          ])
+        )
+        .signers([admin])
+        .rpc();
+
+      const firstPool = await program.account.stakingPool.fetch(first.stakingPool);
+      const secondPool = await program.account.stakingPool.fetch(second.stakingPool);
+      assert.equal(firstPool.totalRewardsDistributed.toNumber(), reward);
+      assert.equal(secondPool.totalRewardsDistributed.toNumber(), 2 * reward);
+      assert.equal(Number((await getAccount(provider.connection, first.rewardVault)).amount), reward);
+      assert.equal(Number((await getAccount(provider.connection, second.rewardVault)).amount), 2 * reward);
+
+      console.log("✅ Funded two staking pools in one transaction");
+    });
+
+    it("should reject a batch whose reward vault does not match its pool", async () => {
+      try {
+        await program.methods
+          .distributeBatch([new anchor.BN(reward)])
+          .accountsStrict({
+            authority: admin.publicKey,
+            rewardMint: usdcMint,
+            rewardSource: adminUsdcAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts(batchMetas([[first.stakingPool, second.rewardVault]]))
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "InvalidPDA");
+        console.log("✅ Correctly rejected mismatched reward vault");
+      }
+    });
+
+    it("should reject a batch with a missing account pair", async () => {
+      try {
+        await program.methods
+          .distributeBatch([new anchor.BN(reward), new anchor.BN(reward)])
+          .accountsStrict({
+            authority: admin.publicKey,
+            rewardMint: usdcMint,
+            rewardSource: adminUsdcAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts(batchMetas([[first.stakingPool, first.rewardVault]]))
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "InvalidBatch");
+        console.log("✅ Correctly rejected incomplete batch");
+      }
+    });
+  });
+
+  describe("Emergency Unstake", () => {
+    let paused: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const reward = 1_000 * 10 ** USDC_DECIMALS;
+
+    const emergencyUnstake = async (user: Keypair) =>
+      program.methods
+        .emergencyUnstake()
+        .accountsStrict({
+          user: user.publicKey,
+          stakingPool: paused.stakingPool,
+          staker: findStaker(paused, user),
+          vltrMint: paused.vltrMint,
+          userVltrAccount: await vltrAccountFor(paused, user),
+          stakeVault: paused.stakeVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+
+    const setPaused = (isPaused: boolean) =>
+      program.methods
+        .pausePool(isPaused)
+        .accountsStrict({ admin: admin.publicKey, stakingPool: paused.stakingPool })
+        .signers([admin])
+        .rpc();
+
+    before(async () => {
+      paused = await createIsolatedPool();
+      await mintAndStake(paused, user1, stakeUnit);
+      await mintAndStake(paused, user2, stakeUnit);
+      await distributeTo(paused, reward);
+    });
+
+    it("should reject emergency unstake while the pool is live", async () => {
+      try {
+        await emergencyUnstake(user1);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "PoolNotPaused");
+        console.log("✅ Correctly rejected emergency unstake on a live pool");
+      }
+    });
+
+    it("should return principal and forfeit rewards while paused", async () => {
+      await setPaused(true);
+      const vltrBefore = await getAccount(provider.connection, await vltrAccountFor(paused, user1));
+
+      await emergencyUnstake(user1);
+
+      const vltrAfter = await getAccount(provider.connection, await vltrAccountFor(paused, user1));
+      assert.equal(Number(vltrAfter.amount) - Number(vltrBefore.amount), stakeUnit);
+
+      const staker = await program.account.staker.fetch(findStaker(paused, user1));
+      assert.equal(staker.stakedAmount.toNumber(), 0);
+      assert.equal(staker.pendingUnclaimed.toNumber(), 0);
+
+      const poolAccount = await program.account.stakingPool.fetch(paused.stakingPool);
+      assert.equal(poolAccount.totalStaked.toNumber(), stakeUnit);
+      assert.equal(poolAccount.stakerCount.toNumber(), 1);
+      assert.equal(
+        poolAccount.totalForfeited.toNumber(),
+        reward / 2,
+        "Half the distribution should roll to the remaining staker"
+      );
+
+      await setPaused(false);
+      console.log("✅ Emergency unstake returned principal while paused");
+    });
+
+    it("should let every staker exit after a full unstake and re-stake", async () => {
+      const cycled = await createIsolatedPool();
+      await mintAndStake(cycled, user1, stakeUnit);
+      await mintAndStake(cycled, user2, stakeUnit);
+
+      // Reusing the Staker PDA after a full exit once left staker_count
+      // short, so the last staker out hit MathUnderflow
+      await unstakeFrom(cycled, user1, stakeUnit);
+      await mintAndStake(cycled, user1, stakeUnit);
+
+      await program.methods
+        .pausePool(true)
+        .accountsStrict({ admin: admin.publicKey, stakingPool: cycled.stakingPool })
+        .signers([admin])
+        .rpc();
+
+      for (const user of [user1, user2]) {
+        await program.methods
+          .emergencyUnstake()
+          .accountsStrict({
+            user: user.publicKey,
+            stakingPool: cycled.stakingPool,
+            staker: findStaker(cycled, user),
+            vltrMint: cycled.vltrMint,
+            userVltrAccount: await vltrAccountFor(cycled, user),
+            stakeVault: cycled.stakeVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user])
+          .rpc();
+      }
+
+      const poolAccount = await program.account.stakingPool.fetch(cycled.stakingPool);
+      assert.equal(poolAccount.totalStaked.toNumber(), 0);
+      assert.equal(poolAccount.stakerCount.toNumber(), 0);
+      console.log("✅ Every staker recovered principal despite the re-stake");
+    });
+  });
+
+  describe("Distributor Role", () => {
+    let delegated: IsolatedPool;
+    const reward = 500 * 10 ** USDC_DECIMALS;
+
+    const setDistributor = (signer: Keypair, distributor: PublicKey) =>
+      program.methods
+        .setDistributor(distributor)
+        .accountsStrict({ admin: signer.publicKey, stakingPool: delegated.stakingPool })
+        .signers([signer])
+        .rpc();
+
+    const distributeAs = async (signer: Keypair, source: PublicKey) => {
+      await mintTo(provider.connection, admin, usdcMint, source, admin, reward);
+
+      await program.methods
+        .distribute(new anchor.BN(reward))
+        .accountsStrict({
+          authority: signer.publicKey,
+          stakingPool: delegated.stakingPool,
+          rewardMint: usdcMint,
+          rewardSource: source,
+          rewardVault: delegated.rewardVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+    };
+
+    before(async () => {
+      delegated = await createIsolatedPool();
+      await mintAndStake(delegated, user2, 10_000 * 10 ** VLTR_DECIMALS);
+    });
+
+    it("should reject set_distributor from non-admin", async () => {
+      try {
+        await setDistributor(user1, user1.publicKey);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "Unauthorized");
+        console.log("✅ Correctly rejected non-admin set_distributor");
+      }
+    });
+
+    it("should let the distributor distribute without admin rights", async () => {
+      await setDistributor(admin, user1.publicKey);
+
+      const poolBefore = await program.account.stakingPool.fetch(delegated.stakingPool);
+      assert.equal(poolBefore.distributor.toBase58(), user1.publicKey.toBase58());
+
+      await distributeAs(user1, user1UsdcAccount);
+
+      const poolAfter = await program.account.stakingPool.fetch(delegated.stakingPool);
+      assert.equal(
+        poolAfter.totalRewardsDistributed.toNumber() - poolBefore.totalRewardsDistributed.toNumber(),
+        reward
+      );
+      assert.equal(poolAfter.admin.toBase58(), admin.publicKey.toBase58());
+      console.log("✅ Distributor funded the pool");
+    });
+
+    it("should still let the admin distribute", async () => {
+      await distributeAs(admin, adminUsdcAccount);
+      console.log("✅ Admin distribution unaffected by distributor");
+    });
+
+    it("should reject distribution once the distributor is removed", async () => {
+      await setDistributor(admin, PublicKey.default);
+
+      try {
+        await distributeAs(user1, user1UsdcAccount);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "Unauthorized");
+        console.log("✅ Removed distributor can no longer distribute");
+      }
+    });
+  });
+
+  describe("Pending Rewards View", () => {
+    let viewed: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+    const reward = 1_000 * 10 ** USDC_DECIMALS;
+
+    const getPending = (user: Keypair) =>
+      program.methods
+        .getPendingRewards()
+        .accountsStrict({
+          stakingPool: viewed.stakingPool,
+          staker: findStaker(viewed, user),
+        })
+        .view();
+
+    before(async () => {
+      viewed = await createIsolatedPool();
+      await mintAndStake(viewed, user1, stakeUnit);
+      await mintAndStake(viewed, user2, stakeUnit);
+    });
+
+    it("should report exactly what claim pays out", async () => {
+      await distributeTo(viewed, reward);
+
+      const pending = await getPending(user1);
+      assert.equal(pending.pendingRewards.toNumber(), reward / 2);
+      assert.equal(pending.stakedAmount.toNumber(), stakeUnit);
+      assert.equal(pending.rewardsClaimed.toNumber(), 0);
+
+      const usdcBefore = Number((await getAccount(provider.connection, user1UsdcAccount)).amount);
+      await claimFrom(viewed, user1, user1UsdcAccount);
+      const claimed =
+        Number((await getAccount(provider.connection, user1UsdcAccount)).amount) - usdcBefore;
+      assert.equal(claimed, pending.pendingRewards.toNumber());
+
+      const after = await getPending(user1);
+      assert.equal(after.pendingRewards.toNumber(), 0);
+      assert.equal(after.rewardsClaimed.toNumber(), claimed);
+
+      console.log(`✅ View reported ${claimed / 10 ** USDC_DECIMALS} USDC, claim paid the same`);
+    });
+  });
+
+  describe("Recount Stakers", () => {
+    let recount: IsolatedPool;
+    const stakeUnit = 10_000 * 10 ** VLTR_DECIMALS;
+
+    const stakerMetas = (users: Keypair[]) =>
+      users.map((u) => ({
+        pubkey: findStaker(recount, u),
+        isWritable: false,
+        isSigner: false,
+      }));
+
+    before(async () => {
+      recount = await createIsolatedPool();
+      await mintAndStake(recount, user1, stakeUnit);
+      await mintAndStake(recount, user2, stakeUnit);
+
+      // Fully exiting and re-staking reuses the Staker PDA
+      await unstakeFrom(recount, user1, stakeUnit);
+      await mintAndStake(recount, user1, stakeUnit);
+    });
+
+    it("should count a returning staker again after a full exit", async () => {
+      const poolAccount = await program.account.stakingPool.fetch(recount.stakingPool);
+      assert.equal(poolAccount.stakerCount.toNumber(), 2, "Count should match active positions");
+      console.log("✅ Re-stake after a full exit kept staker_count in sync");
+    });
+
+    it("should confirm an accurate staker_count", async () => {
+      await program.methods
+        .recountStakers()
+        .accountsStrict({ admin: admin.publicKey, stakingPool: recount.stakingPool })
+        .remainingAccounts(stakerMetas([user1, user2]))
+        .signers([admin])
+        .rpc();
+
+      const after = await program.account.stakingPool.fetch(recount.stakingPool);
+      assert.equal(after.stakerCount.toNumber(), 2, "Count should match active positions");
+
+      console.log(`✅ staker_count recounted as ${after.stakerCount}`);
+    });
+
+    it("should reject an incomplete staker set", async () => {
+      try {
+        await program.methods
+          .recountStakers()
+          .accountsStrict({ admin: admin.publicKey, stakingPool: recount.stakingPool })
+          .remainingAccounts(stakerMetas([user1]))
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "StakerSetMismatch");
+        console.log("✅ Correctly rejected incomplete staker set");
+      }
+    });
+
+    it("should reject a duplicated position standing in for an omitted one", async () => {
+      // user1 and user2 stake the same amount, so [user1, user1] still adds
+      // up to total_staked
+      try {
+        await program.methods
+          .recountStakers()
+          .accountsStrict({ admin: admin.publicKey, stakingPool: recount.stakingPool })
+          .remainingAccounts(stakerMetas([user1, user1]))
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "DuplicateStakerAccount");
+        console.log("✅ Correctly rejected duplicated staker account");
+      }
+    });
+
+    it("should fail to recount from non-admin", async () => {
+      try {
+        await program.methods
+          .recountStakers()
+          .accountsStrict({ admin: user1.publicKey, stakingPool: recount.stakingPool })
+          .remainingAccounts(stakerMetas([user1, user2]))
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.message, "Unauthorized");
+        console.log("✅ Correctly rejected recount from non-admin");
+      }
+    });
+  });
 });
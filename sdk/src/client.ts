@@ -19,7 +19,6 @@ import {
   SendOptions,
 } from "@solana/web3.js";
 import {
-  TOKEN_PROGRAM_ID,
   getAssociatedTokenAddress,
   createAssociatedTokenAccountInstruction,
   getAccount,
@@ -157,7 +156,7 @@ interface VultrProgram {
         instruction: () => Promise<TransactionInstruction>;
       };
     };
-    recordProfit(amount: BN): {
+    recordProfit(amount: BN, expectedNonce: BN): {
       accounts: (accounts: Record<string, PublicKey>) => {
         rpc: (opts?: ConfirmOptions) => Promise<TransactionSignature>;
         instruction: () => Promise<TransactionInstruction>;
@@ -197,7 +196,13 @@ interface VultrProgram {
         instruction: () => Promise<TransactionInstruction>;
       };
     };
-    transferAdmin(): {
+    proposeAdminTransfer(): {
+      accounts: (accounts: Record<string, PublicKey>) => {
+        rpc: (opts?: ConfirmOptions) => Promise<TransactionSignature>;
+        instruction: () => Promise<TransactionInstruction>;
+      };
+    };
+    finalizeAdminTransfer(): {
       accounts: (accounts: Record<string, PublicKey>) => {
         rpc: (opts?: ConfirmOptions) => Promise<TransactionSignature>;
         instruction: () => Promise<TransactionInstruction>;
@@ -329,6 +334,18 @@ export class VultrClient {
     return this.program;
   }
 
+  /**
+   * Get the token program that owns a mint (legacy Token or Token-2022)
+   *
+   * The pool's share mint and vault live under the same program as its
+   * deposit mint, so this one lookup covers every token account of a pool.
+   */
+  public async getTokenProgramId(mint: PublicKey): Promise<PublicKey> {
+    const info = await this.connection.getAccountInfo(mint);
+    if (!info) throw new Error(`Mint not found: ${mint.toBase58()}`);
+    return info.owner;
+  }
+
   // ===========================================================================
   // PDA Helpers
   // ===========================================================================
@@ -655,6 +672,7 @@ export class VultrClient {
 
     const program = this.getProgram();
     const pdas = this.getAllPoolPdas(depositMint);
+    const tokenProgram = await this.getTokenProgramId(depositMint);
 
     return await program.methods
       .initializePool()
@@ -668,7 +686,7 @@ export class VultrClient {
         treasury,
         stakingRewardsVault,
         systemProgram: SystemProgram.programId,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram,
       })
       .rpc(this.confirmOptions);
   }
@@ -702,20 +720,29 @@ export class VultrClient {
       this.wallet.publicKey
     );
 
+    // The deposit fee is routed to the pool's treasury
+    const pool = await this.getPool(depositMint);
+    if (!pool) throw new Error("Pool not found");
+    const tokenProgram = await this.getTokenProgramId(depositMint);
+
     // Get user token accounts
     const userDepositAta = await getAssociatedTokenAddress(
       depositMint,
-      this.wallet.publicKey
+      this.wallet.publicKey,
+      false,
+      tokenProgram
     );
     const userShareAta = await getAssociatedTokenAddress(
       pdas.shareMint.address,
-      this.wallet.publicKey
+      this.wallet.publicKey,
+      false,
+      tokenProgram
     );
 
     // Check if share ATA needs to be created
     const preInstructions: TransactionInstruction[] = [];
     try {
-      await getAccount(this.connection, userShareAta);
+      await getAccount(this.connection, userShareAta, undefined, tokenProgram);
     } catch (error) {
       // Only create ATA if account doesn't exist
       if (error instanceof TokenAccountNotFoundError) {
@@ -724,7 +751,8 @@ export class VultrClient {
             this.wallet.publicKey,
             userShareAta,
             this.wallet.publicKey,
-            pdas.shareMint.address
+            pdas.shareMint.address,
+            tokenProgram
           )
         );
       } else {
@@ -743,8 +771,9 @@ export class VultrClient {
       userDepositAccount: userDepositAta,
       userShareAccount: userShareAta,
       vault: pdas.vault.address,
+      treasury: pool.treasury,
       systemProgram: SystemProgram.programId,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram,
     });
 
     if (preInstructions.length > 0) {
@@ -789,15 +818,20 @@ export class VultrClient {
       pdas.pool.address,
       this.wallet.publicKey
     );
+    const tokenProgram = await this.getTokenProgramId(depositMint);
 
     // Get user token accounts
     const userDepositAta = await getAssociatedTokenAddress(
       depositMint,
-      this.wallet.publicKey
+      this.wallet.publicKey,
+      false,
+      tokenProgram
     );
     const userShareAta = await getAssociatedTokenAddress(
       pdas.shareMint.address,
-      this.wallet.publicKey
+      this.wallet.publicKey,
+      false,
+      tokenProgram
     );
 
     return await program.methods
@@ -811,7 +845,7 @@ export class VultrClient {
         userDepositAccount: userDepositAta,
         userShareAccount: userShareAta,
         vault: pdas.vault.address,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram,
       })
       .rpc(this.confirmOptions);
   }
@@ -936,13 +970,13 @@ export class VultrClient {
   }
 
   /**
-   * Transfer admin to new address (admin only)
+   * Propose an admin transfer (admin only, starts the 24h timelock)
    *
    * @param depositMint - The deposit token mint
-   * @param newAdmin - New admin public key
+   * @param newAdmin - New admin public key; must call finalizeAdminTransfer
    * @returns Transaction signature
    */
-  public async transferAdmin(
+  public async proposeAdminTransfer(
     depositMint: PublicKey,
     newAdmin: PublicKey
   ): Promise<TransactionSignature> {
@@ -952,7 +986,7 @@ export class VultrClient {
     const poolPda = this.getPoolPda(depositMint);
 
     return await program.methods
-      .transferAdmin()
+      .proposeAdminTransfer()
       .accounts({
         admin: this.wallet.publicKey,
         newAdmin,
@@ -960,4 +994,27 @@ export class VultrClient {
       })
       .rpc(this.confirmOptions);
   }
+
+  /**
+   * Finalize a pending admin transfer (pending admin only, after the timelock)
+   *
+   * @param depositMint - The deposit token mint
+   * @returns Transaction signature
+   */
+  public async finalizeAdminTransfer(
+    depositMint: PublicKey
+  ): Promise<TransactionSignature> {
+    if (!this.wallet) throw new Error("Wallet required");
+
+    const program = this.getProgram();
+    const poolPda = this.getPoolPda(depositMint);
+
+    return await program.methods
+      .finalizeAdminTransfer()
+      .accounts({
+        newAdmin: this.wallet.publicKey,
+        pool: poolPda,
+      })
+      .rpc(this.confirmOptions);
+  }
 }
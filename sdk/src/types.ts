@@ -26,6 +26,8 @@ export interface Pool {
   admin: PublicKey;
   /** Bot wallet authorized to call record_profit */
   botWallet: PublicKey;
+  /** Incident-response key (PublicKey.default if unset) */
+  guardian: PublicKey;
   /** Deposit token mint (e.g., USDC) */
   depositMint: PublicKey;
   /** Share token mint (sVLTR) */
@@ -45,6 +47,8 @@ export interface Pool {
   totalProfit: BN;
   /** Total number of liquidations executed */
   totalLiquidations: BN;
+  /** Expected nonce for the next record_profit (replay protection) */
+  nonce: BN;
 
   /** Depositor fee in basis points (default: 8000 = 80%) */
   depositorFeeBps: number;
@@ -52,11 +56,21 @@ export interface Pool {
   stakingFeeBps: number;
   /** Treasury fee in basis points (default: 500 = 5%) */
   treasuryFeeBps: number;
+  /** Deposit fee in basis points, taken from each deposit for the treasury */
+  depositFeeBps: number;
+  /** Withdrawal fee in basis points, kept in the vault for remaining depositors */
+  withdrawalFeeBps: number;
 
   /** Whether the pool is paused */
   isPaused: boolean;
+  /** Granular pause bits: 1 = deposits, 2 = withdrawals, 4 = liquidations */
+  pauseFlags: number;
   /** Maximum pool size in deposit tokens */
   maxPoolSize: BN;
+  /** Maximum cumulative deposits per user (0 = unlimited) */
+  maxDepositPerUser: BN;
+  /** Smallest profit record_profit accepts (0 = any non-zero profit) */
+  minProfitAmount: BN;
 
   /** PDA bumps for efficient derivation */
   bump: number;
@@ -88,6 +102,13 @@ export interface Depositor {
   lastDepositTimestamp: BN;
   /** Timestamp of most recent withdrawal */
   lastWithdrawalTimestamp: BN;
+  /** Timestamp of first ever deposit (never reset) */
+  firstDepositTimestamp: BN;
+
+  /** Total withdrawals ever recorded (ring buffer write cursor) */
+  withdrawalsRecorded: number;
+  /** Ring buffer of the last 8 withdrawals; use getWithdrawalHistory for ordered entries */
+  withdrawalHistory: { amount: BN; timestamp: BN }[];
 
   /** PDA bump */
   bump: number;